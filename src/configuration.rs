@@ -0,0 +1,728 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::constants;
+use crate::readers::*;
+
+// The order in which subshells are listed in an electron configuration
+#[derive(Clone, Copy, PartialEq)]
+pub enum SubshellOrder {
+    // Madelung (Aufbau) filling order, e.g. 1s2 2s2 2p6 3s2 3p6 4s2 3d1
+    Energy,
+    // Grouped by principal quantum number, e.g. 1s2 2s2 2p6 3s2 3p6 3d1 4s2
+    Shell,
+}
+
+impl SubshellOrder {
+    pub fn prompt() -> Self {
+        match read_i32("Enter 1 for energy (Aufbau) order or 2 for shell order: ") {
+            2 => SubshellOrder::Shell,
+            _ => SubshellOrder::Energy,
+        }
+    }
+}
+
+// Whether an electron configuration comes from the Madelung rule or from experimental data
+// Ions are always predicted, since the experimental data is for neutral atoms only
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigurationSource {
+    Predicted,
+    Experimental,
+}
+
+impl Display for ConfigurationSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            ConfigurationSource::Predicted => "predicted",
+            ConfigurationSource::Experimental => "experimental",
+        })
+    }
+}
+
+// What to report about an electron configuration
+#[derive(Clone, Copy, PartialEq)]
+pub enum ConfigurationOutput {
+    // e.g. 1s2 2s2 2p6 3s2 3p6 4s2 3d6
+    Full,
+    // e.g. [Ar] 4s2 3d6
+    Condensed,
+    // One line of orbital boxes per subshell, e.g. 2p [↑↓][↑ ][↑ ], followed by the unpaired electron count
+    OrbitalDiagram,
+    // Unpaired electron count, paramagnetic or diamagnetic, and spin-only magnetic moment
+    Magnetism,
+    // The (n, l, mℓ, mₛ) set of every electron
+    QuantumNumbers,
+    // The (n, l, mℓ, mₛ) set of only the last (differentiating) electron
+    LastElectron,
+}
+
+impl ConfigurationOutput {
+    pub fn prompt() -> Self {
+        match read_i32("Enter 1 for full notation, 2 for condensed (noble gas) notation or 3 for an orbital diagram: ") {
+            2 => ConfigurationOutput::Condensed,
+            3 => ConfigurationOutput::OrbitalDiagram,
+            _ => ConfigurationOutput::Full,
+        }
+    }
+}
+
+// The set of four quantum numbers describing a single electron
+// Signed so that disallowed values entered by the user can still be represented
+pub struct QuantumNumbers {
+    pub n: i32,
+    pub l: i32,
+    pub ml: i32,
+    pub ms: f64,
+}
+
+impl QuantumNumbers {
+    pub fn prompt() -> Self {
+        Self {
+            n: read_i32("Enter the principal quantum number (n): "),
+            l: read_i32("Enter the azimuthal quantum number (l): "),
+            ml: read_i32("Enter the magnetic quantum number (mℓ): "),
+            ms: read_f64("Enter the spin quantum number (mₛ, 0.5 or -0.5): "),
+        }
+    }
+
+    // Gets the reason the set of quantum numbers is not allowed, or None if it is allowed
+    pub fn problem(&self) -> Option<String> {
+        if self.n < 1 {
+            Some(format!("n must be a positive integer, but is {}", self.n))
+        } else if self.l < 0 || self.l >= self.n {
+            Some(format!("l must be between 0 and n - 1 ({}), but is {}", self.n - 1, self.l))
+        } else if self.ml.abs() > self.l {
+            Some(format!("mℓ must be between -l ({}) and +l ({}), but is {}", -self.l, self.l, self.ml))
+        } else if self.ms != 0.5 && self.ms != -0.5 {
+            Some(format!("mₛ must be +½ or -½, but is {}", self.ms))
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for QuantumNumbers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let ms = if self.ms == 0.5 {
+            String::from("+½")
+        } else if self.ms == -0.5 {
+            String::from("-½")
+        } else {
+            self.ms.to_string()
+        };
+
+        write!(f, "n = {}, l = {}, mℓ = {}, mₛ = {}", self.n, self.l, self.ml, ms)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShellType {
+    S,
+    P,
+    D,
+    F,
+}
+
+impl ShellType {
+    // Gets the azimuthal quantum number (l) of the shell type
+    pub fn l(&self) -> u32 {
+        use ShellType::*;
+
+        match self {
+            S => 0,
+            P => 1,
+            D => 2,
+            F => 3,
+        }
+    }
+
+    // Gets the shell type from its spectroscopic letter (e.g. 'd' -> D)
+    pub fn from_letter(letter: char) -> Option<Self> {
+        use ShellType::*;
+
+        match letter {
+            's' => Some(S),
+            'p' => Some(P),
+            'd' => Some(D),
+            'f' => Some(F),
+            _ => None,
+        }
+    }
+
+    // Gets the spectroscopic letter of the shell type (e.g. "d")
+    pub fn letter(&self) -> &'static str {
+        use ShellType::*;
+
+        match self {
+            S => "s",
+            P => "p",
+            D => "d",
+            F => "f",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Shell {
+    pub shell_type: ShellType,
+    pub electrons: u32,
+}
+
+impl Shell {
+    pub fn new(shell_type: ShellType) -> Self {
+        Self {
+            shell_type,
+            electrons: 0,
+        }
+    }
+
+    // Gets all possible shells for a given n
+    pub fn get_possible_shells(n: u32) -> Vec<Shell> {
+        use ShellType::*;
+
+        let mut shells: Vec<Shell> = Vec::new();
+
+        if n > 0 {
+            shells.push(Shell::new(S));
+        }
+
+        if n > 1 {
+            shells.push(Shell::new(P));
+        }
+
+        if n > 2 {
+            shells.push(Shell::new(D));
+        }
+
+        if n > 3 {
+            shells.push(Shell::new(F));
+        }
+
+        shells
+    }
+
+    // Gets the maximum number of electrons for a given shell type
+    pub fn max_electrons(&self) -> u32 {
+        use ShellType::*;
+
+        match self.shell_type {
+            S => 2,
+            P => 6,
+            D => 10,
+            F => 14,
+        }
+    }
+
+    // Gets the number of orbitals in the shell (2l + 1)
+    pub fn orbitals(&self) -> u32 {
+        2 * self.shell_type.l() + 1
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.electrons == self.max_electrons()
+    }
+
+    // Gets the number of unpaired electrons, assuming the orbitals are filled according to Hund's rule
+    pub fn unpaired_electrons(&self) -> u32 {
+        if self.electrons <= self.orbitals() {
+            self.electrons
+        } else {
+            2 * self.orbitals() - self.electrons
+        }
+    }
+
+    // Draws each orbital as a box, filled according to Hund's rule
+    // Every orbital gets one spin-up electron before any of them get a spin-down electron
+    pub fn orbital_diagram(&self) -> String {
+        (0..self.orbitals()).map(|orbital| {
+            let up = if orbital < self.electrons { "↑" } else { " " };
+            let down = if orbital + self.orbitals() < self.electrons { "↓" } else { " " };
+
+            format!("[{}{}]", up, down)
+        }).collect()
+    }
+
+    // Gets the quantum numbers of every electron in the shell, in the order they are added
+    // Orbitals are filled from mℓ = -l to +l with spin up, then again with spin down (Hund's rule)
+    pub fn quantum_numbers(&self, n: u32) -> Vec<QuantumNumbers> {
+        let l = self.shell_type.l() as i32;
+
+        (0..self.electrons).map(|electron| QuantumNumbers {
+            n: n as i32,
+            l,
+            ml: (electron % self.orbitals()) as i32 - l,
+            ms: if electron < self.orbitals() { 0.5 } else { -0.5 },
+        }).collect()
+    }
+}
+
+impl Display for Shell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}{}", self.shell_type.letter(), self.electrons)
+    }
+}
+
+// Represents all the shells in a given energy level (n)
+#[derive(Clone)]
+pub struct EnergyLevel {
+    pub n: u32,
+    pub shells: Vec<Shell>,
+}
+
+impl EnergyLevel {
+    // Creates an energy level with all of its shells empty
+    pub fn new(n: u32) -> Self {
+        Self {
+            n,
+            shells: Shell::get_possible_shells(n),
+        }
+    }
+
+    // Gets the total number of electrons in the energy level
+    pub fn electrons(&self) -> u32 {
+        self.shells.iter().map(|shell| shell.electrons).sum()
+    }
+}
+
+impl Display for EnergyLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut shell_strings = Vec::new();
+
+        for shell in &self.shells {
+            if shell.electrons > 0 {
+                shell_strings.push(format!("{}{}", self.n, shell));
+            }
+        }
+
+        write!(f, "{}", shell_strings.join(" "))
+    }
+}
+
+// Represents all the energy levels in the ground state of an element or ion
+// Subshells are filled in Madelung (n + l, then n) order
+// Elements with a known anomalous ground state use the experimental configuration instead
+#[derive(Clone)]
+pub struct ElectronConfiguration {
+    energy_levels: Vec<EnergyLevel>,
+    atomic_number: u32,
+    charge: i32,
+    source: ConfigurationSource,
+}
+
+impl ElectronConfiguration {
+    // The highest energy level needed to hold the electrons of any known element
+    const MAX_N: u32 = 7;
+
+    // Gets the ground state configuration of a neutral element
+    pub fn new(element: &str) -> Option<Self> {
+        Self::ion(element, 0)
+    }
+
+    // Gets the ground state configuration of an ion, starting from the neutral atom's configuration
+    pub fn ion(element: &str, charge: i32) -> Option<Self> {
+        Self::from_atomic_number(constants::atomic_number(element)?, charge)
+    }
+
+    pub fn from_atomic_number(atomic_number: u32, charge: i32) -> Option<Self> {
+        constants::element_name(atomic_number)?;
+
+        let electrons = u32::try_from((atomic_number as i32).checked_sub(charge)?).ok()?;
+        let mut energy_levels = Self::fill(atomic_number);
+
+        // Overwrite the predicted occupancies with the experimental ones for anomalous elements
+        // Ions are built from these, but the removal order is predicted, so only neutral atoms count as experimental
+        let source = match constants::configuration_exception(atomic_number) {
+            Some(exception) => {
+                for &(n, letter, electrons) in exception {
+                    let shell_type = ShellType::from_letter(letter).expect("Invalid subshell in exceptions table");
+                    Self::shell_mut(&mut energy_levels, n, shell_type).electrons = electrons;
+                }
+
+                if charge == 0 { ConfigurationSource::Experimental } else { ConfigurationSource::Predicted }
+            }
+            None => ConfigurationSource::Predicted,
+        };
+
+        if electrons < atomic_number {
+            Self::remove_electrons(&mut energy_levels, atomic_number - electrons);
+        } else if electrons > atomic_number {
+            Self::add_electrons(&mut energy_levels, electrons - atomic_number)?;
+        }
+
+        Some(Self {
+            energy_levels,
+            atomic_number,
+            charge,
+            source,
+        })
+    }
+
+    pub fn atomic_number(&self) -> u32 {
+        self.atomic_number
+    }
+
+    pub fn charge(&self) -> i32 {
+        self.charge
+    }
+
+    pub fn source(&self) -> ConfigurationSource {
+        self.source
+    }
+
+    pub fn energy_levels(&self) -> &[EnergyLevel] {
+        &self.energy_levels
+    }
+
+    // Gets the total number of electrons
+    pub fn electrons(&self) -> u32 {
+        self.energy_levels.iter().map(EnergyLevel::electrons).sum()
+    }
+
+    // Gets the number of electrons in the given subshell (e.g. 3, D -> electrons in 3d)
+    pub fn occupancy(&self, n: u32, shell_type: ShellType) -> u32 {
+        self.shell(n, shell_type).map_or(0, |shell| shell.electrons)
+    }
+
+    // Gets the shell of the given type in energy level n, if it exists
+    pub fn shell(&self, n: u32, shell_type: ShellType) -> Option<&Shell> {
+        self.energy_levels.get(n.checked_sub(1)? as usize)?
+            .shells.iter()
+            .find(|shell| shell.shell_type == shell_type)
+    }
+
+    // Gets every shell that has electrons in it, along with its n, in the given order
+    pub fn occupied_shells(&self, order: SubshellOrder) -> Vec<(u32, &Shell)> {
+        let mut shells: Vec<(u32, &Shell)> = self.energy_levels.iter()
+            .flat_map(|energy_level| energy_level.shells.iter().map(|shell| (energy_level.n, shell)))
+            .filter(|(_, shell)| shell.electrons > 0)
+            .collect();
+
+        if order == SubshellOrder::Energy {
+            shells.sort_by_key(|(n, shell)| (n + shell.shell_type.l(), *n));
+        }
+
+        shells
+    }
+
+    // Iterates over the occupied shells in energy order
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Shell)> {
+        self.occupied_shells(SubshellOrder::Energy).into_iter()
+    }
+
+    // Gets the highest n with any electrons in it
+    pub fn outermost_n(&self) -> Option<u32> {
+        self.energy_levels.iter().rev()
+            .find(|energy_level| energy_level.electrons() > 0)
+            .map(|energy_level| energy_level.n)
+    }
+
+    // Gets the number of valence electrons
+    // These are the electrons outside the noble gas core, except for filled d and f shells below the outermost occupied shell
+    // e.g. Fe ([Ar] 4s2 3d6) has 8, Zn ([Ar] 4s2 3d10) has 2 and Pd ([Kr] 4d10) has 10
+    pub fn valence_electrons(&self) -> u32 {
+        let outermost_n = match self.outermost_n() {
+            Some(n) => n,
+            None => return 0,
+        };
+        let core = Self::noble_gas_core(self.electrons())
+            .and_then(|core_atomic_number| Self::from_atomic_number(core_atomic_number, 0));
+
+        self.iter()
+            .filter(|(n, shell)| !(shell.is_full() && *n < outermost_n && matches!(shell.shell_type, ShellType::D | ShellType::F)))
+            .map(|(n, shell)| shell.electrons.saturating_sub(core.as_ref().map_or(0, |core| core.occupancy(n, shell.shell_type))))
+            .sum()
+    }
+
+    // Gets the number of core (non-valence) electrons
+    pub fn core_electrons(&self) -> u32 {
+        self.electrons() - self.valence_electrons()
+    }
+
+    // Gets the total number of unpaired electrons across all shells
+    pub fn unpaired_electrons(&self) -> u32 {
+        self.iter().map(|(_, shell)| shell.unpaired_electrons()).sum()
+    }
+
+    // Gets the spin-only magnetic moment (in Bohr magnetons)
+    // Uses the formula μ = √(n(n + 2)), where
+        // μ is the magnetic moment (μB)
+        // n is the number of unpaired electrons
+    pub fn spin_only_moment(&self) -> f64 {
+        let unpaired_electrons = self.unpaired_electrons();
+
+        ((unpaired_electrons * (unpaired_electrons + 2)) as f64).sqrt()
+    }
+
+    // Gets the quantum numbers of every electron, in the given shell order
+    pub fn quantum_numbers(&self, order: SubshellOrder) -> Vec<QuantumNumbers> {
+        self.occupied_shells(order).into_iter()
+            .flat_map(|(n, shell)| shell.quantum_numbers(n))
+            .collect()
+    }
+
+    // Gets the quantum numbers of the differentiating electron
+    // This is the last electron in the highest energy occupied shell
+    pub fn last_electron(&self) -> Option<QuantumNumbers> {
+        self.iter().last().and_then(|(n, shell)| shell.quantum_numbers(n).pop())
+    }
+
+    // Formats the configuration in the given order, as the requested output
+    pub fn format(&self, order: SubshellOrder, output: ConfigurationOutput) -> String {
+        match output {
+            ConfigurationOutput::Full => self.format_subshells(order, None),
+            ConfigurationOutput::Condensed => self.format_subshells(order, Self::noble_gas_core(self.electrons())),
+            ConfigurationOutput::OrbitalDiagram => {
+                let mut lines: Vec<String> = self.occupied_shells(order).into_iter()
+                    .map(|(n, shell)| format!("{}{} {}", n, shell.shell_type.letter(), shell.orbital_diagram()))
+                    .collect();
+
+                lines.push(format!("Unpaired electrons: {}", self.unpaired_electrons()));
+                lines.join("\n")
+            }
+            ConfigurationOutput::Magnetism => {
+                let unpaired_electrons = self.unpaired_electrons();
+
+                format!(
+                    "Unpaired electrons: {}\n{}\nSpin-only magnetic moment: {:.2} μB",
+                    unpaired_electrons,
+                    if unpaired_electrons > 0 { "Paramagnetic" } else { "Diamagnetic" },
+                    self.spin_only_moment(),
+                )
+            }
+            ConfigurationOutput::QuantumNumbers => {
+                let mut electron_strings = Vec::new();
+
+                for (n, shell) in self.occupied_shells(order) {
+                    for quantum_numbers in shell.quantum_numbers(n) {
+                        electron_strings.push(format!("{}{}: {}", n, shell.shell_type.letter(), quantum_numbers));
+                    }
+                }
+
+                electron_strings.join("\n")
+            }
+            ConfigurationOutput::LastElectron => match self.iter().last() {
+                Some((n, shell)) => format!("{}{}: {}", n, shell.shell_type.letter(), self.last_electron().expect("Occupied shell should have electrons")),
+                None => String::from("No electrons"),
+            },
+        }
+    }
+
+    // Formats the occupied subshells, replacing the electrons of the given noble gas core with its symbol
+    // Falls back to full notation if the configuration doesn't contain every subshell of the core
+    fn format_subshells(&self, order: SubshellOrder, core: Option<u32>) -> String {
+        let mut remaining = self.clone();
+        let mut energy_level_strings = Vec::new();
+
+        if let Some(core_atomic_number) = core {
+            for (energy_level, core_level) in remaining.energy_levels.iter_mut().zip(Self::fill(core_atomic_number)) {
+                for (shell, core_shell) in energy_level.shells.iter_mut().zip(core_level.shells) {
+                    match shell.electrons.checked_sub(core_shell.electrons) {
+                        Some(electrons) => shell.electrons = electrons,
+                        None => return self.format_subshells(order, None),
+                    }
+                }
+            }
+
+            energy_level_strings.push(format!("[{}]", constants::element_name(core_atomic_number).expect("Noble gas should have a symbol")));
+        }
+
+        match order {
+            SubshellOrder::Shell => {
+                for energy_level in &remaining.energy_levels {
+                    let energy_level_string = format!("{}", energy_level);
+
+                    if !energy_level_string.is_empty() {
+                        energy_level_strings.push(energy_level_string);
+                    }
+                }
+            }
+            SubshellOrder::Energy => {
+                for (n, shell) in remaining.iter() {
+                    energy_level_strings.push(format!("{}{}", n, shell));
+                }
+            }
+        }
+
+        energy_level_strings.join(" ")
+    }
+
+    // Creates energy levels holding the given number of electrons, filled according to the Madelung rule
+    fn fill(electrons: u32) -> Vec<EnergyLevel> {
+        let mut electrons_remaining = electrons;
+        let mut energy_levels: Vec<EnergyLevel> = (1..=Self::MAX_N).map(EnergyLevel::new).collect();
+
+        // Fill each shell completely before moving on to the next one in Madelung order
+        for (n, shell_type) in Self::madelung_order() {
+            if electrons_remaining == 0 {
+                break;
+            }
+
+            let shell = Self::shell_mut(&mut energy_levels, n, shell_type);
+            let max_electrons = shell.max_electrons();
+
+            if electrons_remaining >= max_electrons {
+                shell.electrons = max_electrons;
+                electrons_remaining -= max_electrons;
+            } else {
+                shell.electrons = electrons_remaining;
+                electrons_remaining = 0;
+            }
+        }
+
+        energy_levels
+    }
+
+    // Removes electrons to form a cation
+    // Electrons come out of the outermost energy level of the atom first, highest l first (e.g. 4p before 4s)
+    // Then they come out of the (n-1)d and (n-2)f shells, so Eu³⁺ is [Xe] 4f6 rather than losing 5p electrons
+    fn remove_electrons(energy_levels: &mut [EnergyLevel], count: u32) {
+        let outermost_n = energy_levels.iter().rev()
+            .find(|energy_level| energy_level.electrons() > 0)
+            .map_or(0, |energy_level| energy_level.n);
+
+        for _ in 0..count {
+            let (_, shell) = energy_levels.iter_mut()
+                .flat_map(|energy_level| {
+                    let n = energy_level.n;
+                    energy_level.shells.iter_mut().map(move |shell| (n, shell))
+                })
+                .filter(|(_, shell)| shell.electrons > 0)
+                .max_by_key(|(n, shell)| Self::removal_priority(*n, shell.shell_type, outermost_n))
+                .expect("Cannot remove more electrons than the atom has");
+
+            shell.electrons -= 1;
+        }
+    }
+
+    // Ranks a shell by how soon it loses electrons when forming a cation, with the highest emptied first
+    // The (n-1)d and (n-2)f shells are emptied from the highest energy down (e.g. 5d before 4f)
+    // Any other shells are emptied from the highest n, then the highest l
+    fn removal_priority(n: u32, shell_type: ShellType, outermost_n: u32) -> (u32, u32, u32) {
+        match shell_type {
+            _ if n == outermost_n => (2, n, shell_type.l()),
+            ShellType::D if n + 1 == outermost_n => (1, n + shell_type.l(), n),
+            ShellType::F if n + 2 == outermost_n => (1, n + shell_type.l(), n),
+            _ => (0, n, shell_type.l()),
+        }
+    }
+
+    // Adds electrons to form an anion, continuing to fill shells in Madelung order
+    // Returns None if there is no room left for the electrons
+    fn add_electrons(energy_levels: &mut [EnergyLevel], count: u32) -> Option<()> {
+        for _ in 0..count {
+            let (n, shell_type) = Self::madelung_order().into_iter()
+                .find(|&(n, shell_type)| !Self::shell_mut(energy_levels, n, shell_type).is_full())?;
+
+            Self::shell_mut(energy_levels, n, shell_type).electrons += 1;
+        }
+
+        Some(())
+    }
+
+    // Gets the atomic number of the heaviest noble gas with fewer electrons than given, if any
+    fn noble_gas_core(electrons: u32) -> Option<u32> {
+        [86, 54, 36, 18, 10, 2].into_iter().find(|&noble_gas| noble_gas < electrons)
+    }
+
+    // Gets the shell of the given type in energy level n
+    fn shell_mut(energy_levels: &mut [EnergyLevel], n: u32, shell_type: ShellType) -> &mut Shell {
+        energy_levels[n as usize - 1].shells.iter_mut()
+            .find(|shell| shell.shell_type == shell_type)
+            .expect("Shell type should exist in energy level")
+    }
+
+    // Gets every (n, shell type) pair sorted by increasing n + l, then by increasing n
+    fn madelung_order() -> Vec<(u32, ShellType)> {
+        let mut shells: Vec<(u32, ShellType)> = (1..=Self::MAX_N)
+            .flat_map(|n| Shell::get_possible_shells(n).into_iter().map(move |shell| (n, shell.shell_type)))
+            .collect();
+
+        shells.sort_by_key(|(n, shell_type)| (n + shell_type.l(), *n));
+
+        shells
+    }
+}
+
+// Displays the full configuration in energy order
+impl Display for ElectronConfiguration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.format(SubshellOrder::Energy, ConfigurationOutput::Full))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_occupancy() {
+        let iron = ElectronConfiguration::new("Fe").unwrap();
+
+        assert_eq!(iron.occupancy(3, ShellType::D), 6);
+        assert_eq!(iron.occupancy(4, ShellType::S), 2);
+        assert_eq!(iron.occupancy(4, ShellType::P), 0);
+        assert_eq!(iron.occupancy(9, ShellType::S), 0);
+        assert_eq!(iron.electrons(), 26);
+        assert_eq!(iron.iter().count(), 7);
+    }
+
+    #[test]
+    fn test_valence_and_core_electrons() {
+        let cases = [
+            ("H", 0, 1), ("C", 0, 4), ("Ne", 0, 8), ("Fe", 0, 8), ("Zn", 0, 2), ("Ga", 0, 3), ("Br", 0, 7), ("Cl", -1, 8),
+            // Anomalous configurations count the electrons they actually have outside the core
+            ("Pd", 0, 10), ("Cr", 0, 6), ("Cu", 0, 1), ("Pb", 0, 4), ("Fe", 3, 5),
+        ];
+
+        for (element, charge, valence_electrons) in cases {
+            let config = ElectronConfiguration::ion(element, charge).unwrap();
+
+            assert_eq!(config.valence_electrons(), valence_electrons, "{}{:+}", element, charge);
+            assert_eq!(config.core_electrons(), config.electrons() - valence_electrons, "{}{:+}", element, charge);
+        }
+    }
+
+    #[test]
+    fn test_cations() {
+        let full = |element: &str, charge: i32| ElectronConfiguration::ion(element, charge).unwrap().to_string();
+
+        assert_eq!(full("Fe", 3), "1s2 2s2 2p6 3s2 3p6 3d5");
+        assert_eq!(full("Sn", 4), full("Cd", 0).replace(" 5s2", ""));
+        // The f electrons go before the filled 5p and 6p shells
+        assert_eq!(full("Eu", 3), format!("{} 4f6", full("Xe", 0)));
+        assert_eq!(full("Ce", 4), full("Xe", 0));
+        assert_eq!(full("U", 4), format!("{} 5f2", full("Rn", 0)));
+    }
+
+    #[test]
+    fn test_source() {
+        let source = |element: &str, charge: i32| ElectronConfiguration::ion(element, charge).unwrap().source();
+
+        assert_eq!(source("Cr", 0), ConfigurationSource::Experimental);
+        assert_eq!(source("Fe", 0), ConfigurationSource::Predicted);
+        assert_eq!(source("Cr", 3), ConfigurationSource::Predicted);
+        assert_eq!(source("Cu", 1), ConfigurationSource::Predicted);
+    }
+
+    #[test]
+    fn test_extreme_charges() {
+        assert!(ElectronConfiguration::ion("Fe", i32::MIN).is_none());
+        assert!(ElectronConfiguration::ion("Fe", i32::MAX).is_none());
+        assert!(ElectronConfiguration::ion("Fe", 27).is_none());
+    }
+
+    #[test]
+    fn test_condensed() {
+        let condensed = |element: &str, charge: i32| ElectronConfiguration::ion(element, charge).unwrap().format(SubshellOrder::Energy, ConfigurationOutput::Condensed);
+
+        assert_eq!(condensed("Fe", 0), "[Ar] 4s2 3d6");
+        assert_eq!(condensed("Eu", 3), "[Xe] 4f6");
+        assert_eq!(condensed("U", 4), "[Rn] 5f2");
+
+        // Every cation of every element has a condensed form that doesn't panic
+        for atomic_number in 1..=118 {
+            for charge in 0..=atomic_number as i32 {
+                ElectronConfiguration::from_atomic_number(atomic_number, charge).unwrap().format(SubshellOrder::Shell, ConfigurationOutput::Condensed);
+            }
+        }
+    }
+}
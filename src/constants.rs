@@ -1,254 +1,1034 @@
-pub const SPEED_OF_LIGHT: f64 = 2.998e8;
-pub const AVOGADRO: f64 = 6.022e23;
-pub const PLANCK: f64 = 6.626e-34;
-pub const RYDBERG: f64 = -2.178e-18;
+use std::fmt::{Display, Formatter, Result};
+
+use crate::quantity::*;
+
+// Exact by the definition of the SI units
+pub const SPEED_OF_LIGHT: Quantity<MetrePerSecond> = Quantity::new(299_792_458.0);
+pub const AVOGADRO: Quantity<PerMole> = Quantity::new(6.022_140_76e23);
+pub const PLANCK: Quantity<JouleSecond> = Quantity::new(6.626_070_15e-34);
+// The Rydberg energy hcR∞, with its CODATA 2018 standard uncertainty
+pub const RYDBERG: Quantity<Joule> = Quantity::measured(-2.179_872_361_103_5e-18, 14).with_uncertainty(4.2e-30);
+pub const ELECTRON_VOLT: Quantity<Joule> = Quantity::new(1.602176634e-19);
+// The thermochemical calorie
+pub const CALORIE: Quantity<Joule> = Quantity::new(4.184);
+
+// The block of the periodic table an element sits in, named after the subshell being filled
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Block {
+    S,
+    P,
+    D,
+    F,
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            Block::S => "s",
+            Block::P => "p",
+            Block::D => "d",
+            Block::F => "f",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Category {
+    AlkaliMetal,
+    AlkalineEarthMetal,
+    TransitionMetal,
+    PostTransitionMetal,
+    Lanthanide,
+    Actinide,
+    Metalloid,
+    Nonmetal,
+    Halogen,
+    NobleGas,
+    // Superheavy elements whose chemistry has not been established
+    Unknown,
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            Category::AlkaliMetal => "alkali metal",
+            Category::AlkalineEarthMetal => "alkaline earth metal",
+            Category::TransitionMetal => "transition metal",
+            Category::PostTransitionMetal => "post-transition metal",
+            Category::Lanthanide => "lanthanide",
+            Category::Actinide => "actinide",
+            Category::Metalloid => "metalloid",
+            Category::Nonmetal => "nonmetal",
+            Category::Halogen => "halogen",
+            Category::NobleGas => "noble gas",
+            Category::Unknown => "unknown",
+        })
+    }
+}
+
+// A single entry in the periodic table
+// Atomic weights are IUPAC standard atomic weights (in g/mol)
+// For elements with no stable isotopes, the mass number of the longest-lived isotope is used instead
+// Lanthanides and actinides (other than Lu and Lr) have no group
+#[derive(Debug, PartialEq)]
+pub struct Element {
+    pub atomic_number: u32,
+    pub symbol: &'static str,
+    pub name: &'static str,
+    pub atomic_weight: f64,
+    pub group: Option<u32>,
+    pub period: u32,
+    pub block: Block,
+    pub category: Category,
+}
+
+impl Element {
+    pub fn from_atomic_number(atomic_number: u32) -> Option<&'static Element> {
+        ELEMENTS.get(atomic_number.checked_sub(1)? as usize)
+    }
+
+    // Symbols are case-sensitive, since e.g. "Co" and "CO" mean different things
+    pub fn from_symbol(symbol: &str) -> Option<&'static Element> {
+        ELEMENTS.iter().find(|element| element.symbol == symbol)
+    }
+
+    // Names are case-insensitive, and accept common alternate spellings
+    pub fn from_name(name: &str) -> Option<&'static Element> {
+        let name = match name.to_lowercase().as_str() {
+            "aluminium" => String::from("aluminum"),
+            "cesium" => String::from("caesium"),
+            "sulphur" => String::from("sulfur"),
+            name => name.to_string(),
+        };
+
+        ELEMENTS.iter().find(|element| element.name.to_lowercase() == name)
+    }
+
+    // Looks up an element by its symbol, name or atomic number (e.g. "Fe", "Iron" or "26")
+    pub fn lookup(element: &str) -> Option<&'static Element> {
+        match element.parse() {
+            Ok(atomic_number) => Self::from_atomic_number(atomic_number),
+            Err(_) => Self::from_symbol(element).or_else(|| Self::from_name(element)),
+        }
+    }
+
+    pub fn properties(&self) -> &'static ElementProperties {
+        &PROPERTIES[self.atomic_number as usize - 1]
+    }
+
+    // Gets the known isotopes of the element, in order of mass number
+    pub fn isotopes(&self) -> impl Iterator<Item = &'static Isotope> + '_ {
+        ISOTOPES.iter().filter(|isotope| isotope.atomic_number == self.atomic_number)
+    }
+
+    // Gets the nth ionization energy (in kJ/mol), e.g. n = 2 removes the second electron
+    pub fn ionization_energy(&self, n: usize) -> Option<f64> {
+        self.properties().ionization_energies.get(n.checked_sub(1)?).copied()
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "{} ({}), atomic number {}", self.name, self.symbol, self.atomic_number)?;
+        writeln!(f, "Atomic weight: {} g/mol", self.atomic_weight)?;
+        match self.group {
+            Some(group) => writeln!(f, "Group {}, period {}, {}-block", group, self.period, self.block)?,
+            None => writeln!(f, "Period {}, {}-block", self.period, self.block)?,
+        }
+        write!(f, "Category: {}", self.category)?;
+
+        let properties = self.properties();
+        for property in Property::ALL {
+            match (property, property.value(self)) {
+                (Property::IonicRadius, Some(radius)) => {
+                    let (charge, _) = properties.ionic_radius.expect("Ionic radius should have a charge");
+                    write!(f, "\n{}: {} {} ({}{}{})", property, radius, property.unit(), self.symbol, charge.abs(), if charge < 0 { '-' } else { '+' })?;
+                }
+                (Property::IonizationEnergy, Some(_)) => {
+                    let energies: Vec<String> = properties.ionization_energies.iter().map(f64::to_string).collect();
+                    write!(f, "\nIonization energies: {} {}", energies.join(", "), property.unit())?;
+
+                    // Only the first few are tabulated past Ne
+                    if energies.len() < self.atomic_number as usize {
+                        write!(f, " (first {} of {} listed)", energies.len(), self.atomic_number)?;
+                    }
+                }
+                (_, Some(value)) => write!(f, "\n{}: {} {}", property, value, property.unit())?,
+                (_, None) => {}
+            }
+        }
+
+        if !properties.oxidation_states.is_empty() {
+            let states: Vec<String> = properties.oxidation_states.iter().map(|state| format!("{:+}", state)).collect();
+            write!(f, "\nCommon oxidation states: {}", states.join(", "))?;
+        }
+
+        for isotope in self.isotopes() {
+            write!(f, "\nIsotope {}", isotope)?;
+        }
+
+        write!(f, "\nSources:")?;
+        for property in Property::ALL.into_iter().filter(|property| property.value(self).is_some()) {
+            write!(f, "\n    {}: {}", property, property.source())?;
+        }
+        if !properties.oxidation_states.is_empty() {
+            write!(f, "\n    Oxidation states: {}", OXIDATION_STATE_SOURCE)?;
+        }
+        if self.isotopes().next().is_some() {
+            write!(f, "\n    Isotopes: {}", ISOTOPE_SOURCE)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Measured properties of an element, with None (or empty) where no reliable value exists
+// Units and sources for each dataset are given by Property
+pub struct ElementProperties {
+    pub electronegativity: Option<f64>,
+    pub atomic_radius: Option<f64>,
+    pub covalent_radius: Option<f64>,
+    // The radius of the most common ion, as (charge, radius)
+    pub ionic_radius: Option<(i32, f64)>,
+    // Successive ionization energies, starting from the first
+    // Complete for H to Ne, then only the first few are listed
+    pub ionization_energies: &'static [f64],
+    // Energy released when the atom gains an electron, or None if the anion is unbound
+    pub electron_affinity: Option<f64>,
+    pub density: Option<f64>,
+    pub melting_point: Option<f64>,
+    pub boiling_point: Option<f64>,
+    pub oxidation_states: &'static [i32],
+}
+
+pub const OXIDATION_STATE_SOURCE: &str = "N. N. Greenwood and A. Earnshaw, Chemistry of the Elements, 2nd ed. (1997)";
+
+// A numeric property that can be looked up and compared across elements
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Property {
+    Electronegativity,
+    AtomicRadius,
+    CovalentRadius,
+    IonicRadius,
+    // The first ionization energy
+    IonizationEnergy,
+    ElectronAffinity,
+    Density,
+    MeltingPoint,
+    BoilingPoint,
+}
+
+impl Property {
+    pub const ALL: [Property; 9] = [
+        Property::Electronegativity,
+        Property::AtomicRadius,
+        Property::CovalentRadius,
+        Property::IonicRadius,
+        Property::IonizationEnergy,
+        Property::ElectronAffinity,
+        Property::Density,
+        Property::MeltingPoint,
+        Property::BoilingPoint,
+    ];
+
+    pub fn value(self, element: &Element) -> Option<f64> {
+        let properties = element.properties();
+
+        match self {
+            Property::Electronegativity => properties.electronegativity,
+            Property::AtomicRadius => properties.atomic_radius,
+            Property::CovalentRadius => properties.covalent_radius,
+            Property::IonicRadius => properties.ionic_radius.map(|(_, radius)| radius),
+            Property::IonizationEnergy => element.ionization_energy(1),
+            Property::ElectronAffinity => properties.electron_affinity,
+            Property::Density => properties.density,
+            Property::MeltingPoint => properties.melting_point,
+            Property::BoilingPoint => properties.boiling_point,
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            Property::Electronegativity => "(Pauling)",
+            Property::AtomicRadius | Property::CovalentRadius | Property::IonicRadius => "pm",
+            Property::IonizationEnergy | Property::ElectronAffinity => "kJ/mol",
+            // Gases are given at 0 °C and 1 atm
+            Property::Density => "g/cm³",
+            Property::MeltingPoint | Property::BoilingPoint => "K",
+        }
+    }
+
+    pub fn source(self) -> &'static str {
+        match self {
+            Property::Electronegativity => "Pauling scale, CRC Handbook of Chemistry and Physics, 97th ed. (2016)",
+            Property::AtomicRadius => "Empirical radii, J. C. Slater, J. Chem. Phys. 41, 3199 (1964)",
+            Property::CovalentRadius => "B. Cordero et al., Dalton Trans. 2832 (2008)",
+            Property::IonicRadius => "Six-coordinate (high spin) radii, R. D. Shannon, Acta Cryst. A32, 751 (1976)",
+            Property::IonizationEnergy => "NIST Atomic Spectra Database, via CRC Handbook of Chemistry and Physics, 97th ed. (2016); successive energies are complete for H to Ne only",
+            Property::ElectronAffinity => "T. Andersen, H. K. Haugen and H. Hotop, J. Phys. Chem. Ref. Data 28, 1511 (1999), via CRC Handbook",
+            Property::Density | Property::MeltingPoint | Property::BoilingPoint => "CRC Handbook of Chemistry and Physics, 97th ed. (2016)",
+        }
+    }
+
+    // Describes which elements the dataset covers, if it has known gaps
+    pub fn coverage(self) -> Option<&'static str> {
+        match self {
+            Property::IonizationEnergy => Some("first energy up to Lr; successive energies complete for H to Ne, only the first few for heavier elements"),
+            _ => None,
+        }
+    }
+
+    // Gets every element with a known value, from highest to lowest
+    pub fn ranked(self) -> Vec<(&'static Element, f64)> {
+        let mut ranked: Vec<(&'static Element, f64)> = ELEMENTS.iter()
+            .filter_map(|element| self.value(element).map(|value| (element, value)))
+            .collect();
+        ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        ranked
+    }
+}
+
+impl Display for Property {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            Property::Electronegativity => "Electronegativity",
+            Property::AtomicRadius => "Atomic radius",
+            Property::CovalentRadius => "Covalent radius",
+            Property::IonicRadius => "Ionic radius",
+            Property::IonizationEnergy => "First ionization energy",
+            Property::ElectronAffinity => "Electron affinity",
+            Property::Density => "Density",
+            Property::MeltingPoint => "Melting point",
+            Property::BoilingPoint => "Boiling point",
+        })
+    }
+}
+
+// Every known element, in order of atomic number
+pub const ELEMENTS: [Element; 118] = [
+    Element { atomic_number: 1, symbol: "H", name: "Hydrogen", atomic_weight: 1.008, group: Some(1), period: 1, block: Block::S, category: Category::Nonmetal },
+    Element { atomic_number: 2, symbol: "He", name: "Helium", atomic_weight: 4.0026, group: Some(18), period: 1, block: Block::S, category: Category::NobleGas },
+    Element { atomic_number: 3, symbol: "Li", name: "Lithium", atomic_weight: 6.94, group: Some(1), period: 2, block: Block::S, category: Category::AlkaliMetal },
+    Element { atomic_number: 4, symbol: "Be", name: "Beryllium", atomic_weight: 9.0122, group: Some(2), period: 2, block: Block::S, category: Category::AlkalineEarthMetal },
+    Element { atomic_number: 5, symbol: "B", name: "Boron", atomic_weight: 10.81, group: Some(13), period: 2, block: Block::P, category: Category::Metalloid },
+    Element { atomic_number: 6, symbol: "C", name: "Carbon", atomic_weight: 12.011, group: Some(14), period: 2, block: Block::P, category: Category::Nonmetal },
+    Element { atomic_number: 7, symbol: "N", name: "Nitrogen", atomic_weight: 14.007, group: Some(15), period: 2, block: Block::P, category: Category::Nonmetal },
+    Element { atomic_number: 8, symbol: "O", name: "Oxygen", atomic_weight: 15.999, group: Some(16), period: 2, block: Block::P, category: Category::Nonmetal },
+    Element { atomic_number: 9, symbol: "F", name: "Fluorine", atomic_weight: 18.998, group: Some(17), period: 2, block: Block::P, category: Category::Halogen },
+    Element { atomic_number: 10, symbol: "Ne", name: "Neon", atomic_weight: 20.180, group: Some(18), period: 2, block: Block::P, category: Category::NobleGas },
+    Element { atomic_number: 11, symbol: "Na", name: "Sodium", atomic_weight: 22.990, group: Some(1), period: 3, block: Block::S, category: Category::AlkaliMetal },
+    Element { atomic_number: 12, symbol: "Mg", name: "Magnesium", atomic_weight: 24.305, group: Some(2), period: 3, block: Block::S, category: Category::AlkalineEarthMetal },
+    Element { atomic_number: 13, symbol: "Al", name: "Aluminum", atomic_weight: 26.982, group: Some(13), period: 3, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 14, symbol: "Si", name: "Silicon", atomic_weight: 28.085, group: Some(14), period: 3, block: Block::P, category: Category::Metalloid },
+    Element { atomic_number: 15, symbol: "P", name: "Phosphorus", atomic_weight: 30.974, group: Some(15), period: 3, block: Block::P, category: Category::Nonmetal },
+    Element { atomic_number: 16, symbol: "S", name: "Sulfur", atomic_weight: 32.06, group: Some(16), period: 3, block: Block::P, category: Category::Nonmetal },
+    Element { atomic_number: 17, symbol: "Cl", name: "Chlorine", atomic_weight: 35.45, group: Some(17), period: 3, block: Block::P, category: Category::Halogen },
+    Element { atomic_number: 18, symbol: "Ar", name: "Argon", atomic_weight: 39.948, group: Some(18), period: 3, block: Block::P, category: Category::NobleGas },
+    Element { atomic_number: 19, symbol: "K", name: "Potassium", atomic_weight: 39.098, group: Some(1), period: 4, block: Block::S, category: Category::AlkaliMetal },
+    Element { atomic_number: 20, symbol: "Ca", name: "Calcium", atomic_weight: 40.078, group: Some(2), period: 4, block: Block::S, category: Category::AlkalineEarthMetal },
+    Element { atomic_number: 21, symbol: "Sc", name: "Scandium", atomic_weight: 44.956, group: Some(3), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 22, symbol: "Ti", name: "Titanium", atomic_weight: 47.867, group: Some(4), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 23, symbol: "V", name: "Vanadium", atomic_weight: 50.942, group: Some(5), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 24, symbol: "Cr", name: "Chromium", atomic_weight: 51.996, group: Some(6), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 25, symbol: "Mn", name: "Manganese", atomic_weight: 54.938, group: Some(7), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 26, symbol: "Fe", name: "Iron", atomic_weight: 55.845, group: Some(8), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 27, symbol: "Co", name: "Cobalt", atomic_weight: 58.933, group: Some(9), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 28, symbol: "Ni", name: "Nickel", atomic_weight: 58.693, group: Some(10), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 29, symbol: "Cu", name: "Copper", atomic_weight: 63.546, group: Some(11), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 30, symbol: "Zn", name: "Zinc", atomic_weight: 65.38, group: Some(12), period: 4, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 31, symbol: "Ga", name: "Gallium", atomic_weight: 69.723, group: Some(13), period: 4, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 32, symbol: "Ge", name: "Germanium", atomic_weight: 72.630, group: Some(14), period: 4, block: Block::P, category: Category::Metalloid },
+    Element { atomic_number: 33, symbol: "As", name: "Arsenic", atomic_weight: 74.922, group: Some(15), period: 4, block: Block::P, category: Category::Metalloid },
+    Element { atomic_number: 34, symbol: "Se", name: "Selenium", atomic_weight: 78.971, group: Some(16), period: 4, block: Block::P, category: Category::Nonmetal },
+    Element { atomic_number: 35, symbol: "Br", name: "Bromine", atomic_weight: 79.904, group: Some(17), period: 4, block: Block::P, category: Category::Halogen },
+    Element { atomic_number: 36, symbol: "Kr", name: "Krypton", atomic_weight: 83.798, group: Some(18), period: 4, block: Block::P, category: Category::NobleGas },
+    Element { atomic_number: 37, symbol: "Rb", name: "Rubidium", atomic_weight: 85.468, group: Some(1), period: 5, block: Block::S, category: Category::AlkaliMetal },
+    Element { atomic_number: 38, symbol: "Sr", name: "Strontium", atomic_weight: 87.62, group: Some(2), period: 5, block: Block::S, category: Category::AlkalineEarthMetal },
+    Element { atomic_number: 39, symbol: "Y", name: "Yttrium", atomic_weight: 88.906, group: Some(3), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 40, symbol: "Zr", name: "Zirconium", atomic_weight: 91.224, group: Some(4), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 41, symbol: "Nb", name: "Niobium", atomic_weight: 92.906, group: Some(5), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 42, symbol: "Mo", name: "Molybdenum", atomic_weight: 95.95, group: Some(6), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 43, symbol: "Tc", name: "Technetium", atomic_weight: 98.0, group: Some(7), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 44, symbol: "Ru", name: "Ruthenium", atomic_weight: 101.07, group: Some(8), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 45, symbol: "Rh", name: "Rhodium", atomic_weight: 102.91, group: Some(9), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 46, symbol: "Pd", name: "Palladium", atomic_weight: 106.42, group: Some(10), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 47, symbol: "Ag", name: "Silver", atomic_weight: 107.87, group: Some(11), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 48, symbol: "Cd", name: "Cadmium", atomic_weight: 112.41, group: Some(12), period: 5, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 49, symbol: "In", name: "Indium", atomic_weight: 114.82, group: Some(13), period: 5, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 50, symbol: "Sn", name: "Tin", atomic_weight: 118.71, group: Some(14), period: 5, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 51, symbol: "Sb", name: "Antimony", atomic_weight: 121.76, group: Some(15), period: 5, block: Block::P, category: Category::Metalloid },
+    Element { atomic_number: 52, symbol: "Te", name: "Tellurium", atomic_weight: 127.60, group: Some(16), period: 5, block: Block::P, category: Category::Metalloid },
+    Element { atomic_number: 53, symbol: "I", name: "Iodine", atomic_weight: 126.90, group: Some(17), period: 5, block: Block::P, category: Category::Halogen },
+    Element { atomic_number: 54, symbol: "Xe", name: "Xenon", atomic_weight: 131.29, group: Some(18), period: 5, block: Block::P, category: Category::NobleGas },
+    Element { atomic_number: 55, symbol: "Cs", name: "Caesium", atomic_weight: 132.91, group: Some(1), period: 6, block: Block::S, category: Category::AlkaliMetal },
+    Element { atomic_number: 56, symbol: "Ba", name: "Barium", atomic_weight: 137.33, group: Some(2), period: 6, block: Block::S, category: Category::AlkalineEarthMetal },
+    Element { atomic_number: 57, symbol: "La", name: "Lanthanum", atomic_weight: 138.91, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 58, symbol: "Ce", name: "Cerium", atomic_weight: 140.12, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 59, symbol: "Pr", name: "Praseodymium", atomic_weight: 140.91, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 60, symbol: "Nd", name: "Neodymium", atomic_weight: 144.24, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 61, symbol: "Pm", name: "Promethium", atomic_weight: 145.0, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 62, symbol: "Sm", name: "Samarium", atomic_weight: 150.36, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 63, symbol: "Eu", name: "Europium", atomic_weight: 151.96, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 64, symbol: "Gd", name: "Gadolinium", atomic_weight: 157.25, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 65, symbol: "Tb", name: "Terbium", atomic_weight: 158.93, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 66, symbol: "Dy", name: "Dysprosium", atomic_weight: 162.50, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 67, symbol: "Ho", name: "Holmium", atomic_weight: 164.93, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 68, symbol: "Er", name: "Erbium", atomic_weight: 167.26, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 69, symbol: "Tm", name: "Thulium", atomic_weight: 168.93, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 70, symbol: "Yb", name: "Ytterbium", atomic_weight: 173.05, group: None, period: 6, block: Block::F, category: Category::Lanthanide },
+    Element { atomic_number: 71, symbol: "Lu", name: "Lutetium", atomic_weight: 174.97, group: Some(3), period: 6, block: Block::D, category: Category::Lanthanide },
+    Element { atomic_number: 72, symbol: "Hf", name: "Hafnium", atomic_weight: 178.49, group: Some(4), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 73, symbol: "Ta", name: "Tantalum", atomic_weight: 180.95, group: Some(5), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 74, symbol: "W", name: "Tungsten", atomic_weight: 183.84, group: Some(6), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 75, symbol: "Re", name: "Rhenium", atomic_weight: 186.21, group: Some(7), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 76, symbol: "Os", name: "Osmium", atomic_weight: 190.23, group: Some(8), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 77, symbol: "Ir", name: "Iridium", atomic_weight: 192.22, group: Some(9), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 78, symbol: "Pt", name: "Platinum", atomic_weight: 195.08, group: Some(10), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 79, symbol: "Au", name: "Gold", atomic_weight: 196.97, group: Some(11), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 80, symbol: "Hg", name: "Mercury", atomic_weight: 200.59, group: Some(12), period: 6, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 81, symbol: "Tl", name: "Thallium", atomic_weight: 204.38, group: Some(13), period: 6, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 82, symbol: "Pb", name: "Lead", atomic_weight: 207.2, group: Some(14), period: 6, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 83, symbol: "Bi", name: "Bismuth", atomic_weight: 208.98, group: Some(15), period: 6, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 84, symbol: "Po", name: "Polonium", atomic_weight: 209.0, group: Some(16), period: 6, block: Block::P, category: Category::PostTransitionMetal },
+    Element { atomic_number: 85, symbol: "At", name: "Astatine", atomic_weight: 210.0, group: Some(17), period: 6, block: Block::P, category: Category::Halogen },
+    Element { atomic_number: 86, symbol: "Rn", name: "Radon", atomic_weight: 222.0, group: Some(18), period: 6, block: Block::P, category: Category::NobleGas },
+    Element { atomic_number: 87, symbol: "Fr", name: "Francium", atomic_weight: 223.0, group: Some(1), period: 7, block: Block::S, category: Category::AlkaliMetal },
+    Element { atomic_number: 88, symbol: "Ra", name: "Radium", atomic_weight: 226.0, group: Some(2), period: 7, block: Block::S, category: Category::AlkalineEarthMetal },
+    Element { atomic_number: 89, symbol: "Ac", name: "Actinium", atomic_weight: 227.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 90, symbol: "Th", name: "Thorium", atomic_weight: 232.04, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 91, symbol: "Pa", name: "Protactinium", atomic_weight: 231.04, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 92, symbol: "U", name: "Uranium", atomic_weight: 238.03, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 93, symbol: "Np", name: "Neptunium", atomic_weight: 237.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 94, symbol: "Pu", name: "Plutonium", atomic_weight: 244.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 95, symbol: "Am", name: "Americium", atomic_weight: 243.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 96, symbol: "Cm", name: "Curium", atomic_weight: 247.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 97, symbol: "Bk", name: "Berkelium", atomic_weight: 247.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 98, symbol: "Cf", name: "Californium", atomic_weight: 251.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 99, symbol: "Es", name: "Einsteinium", atomic_weight: 252.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 100, symbol: "Fm", name: "Fermium", atomic_weight: 257.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 101, symbol: "Md", name: "Mendelevium", atomic_weight: 258.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 102, symbol: "No", name: "Nobelium", atomic_weight: 259.0, group: None, period: 7, block: Block::F, category: Category::Actinide },
+    Element { atomic_number: 103, symbol: "Lr", name: "Lawrencium", atomic_weight: 266.0, group: Some(3), period: 7, block: Block::D, category: Category::Actinide },
+    Element { atomic_number: 104, symbol: "Rf", name: "Rutherfordium", atomic_weight: 267.0, group: Some(4), period: 7, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 105, symbol: "Db", name: "Dubnium", atomic_weight: 268.0, group: Some(5), period: 7, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 106, symbol: "Sg", name: "Seaborgium", atomic_weight: 269.0, group: Some(6), period: 7, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 107, symbol: "Bh", name: "Bohrium", atomic_weight: 270.0, group: Some(7), period: 7, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 108, symbol: "Hs", name: "Hassium", atomic_weight: 269.0, group: Some(8), period: 7, block: Block::D, category: Category::TransitionMetal },
+    Element { atomic_number: 109, symbol: "Mt", name: "Meitnerium", atomic_weight: 278.0, group: Some(9), period: 7, block: Block::D, category: Category::Unknown },
+    Element { atomic_number: 110, symbol: "Ds", name: "Darmstadtium", atomic_weight: 281.0, group: Some(10), period: 7, block: Block::D, category: Category::Unknown },
+    Element { atomic_number: 111, symbol: "Rg", name: "Roentgenium", atomic_weight: 282.0, group: Some(11), period: 7, block: Block::D, category: Category::Unknown },
+    Element { atomic_number: 112, symbol: "Cn", name: "Copernicium", atomic_weight: 285.0, group: Some(12), period: 7, block: Block::D, category: Category::Unknown },
+    Element { atomic_number: 113, symbol: "Nh", name: "Nihonium", atomic_weight: 286.0, group: Some(13), period: 7, block: Block::P, category: Category::Unknown },
+    Element { atomic_number: 114, symbol: "Fl", name: "Flerovium", atomic_weight: 289.0, group: Some(14), period: 7, block: Block::P, category: Category::Unknown },
+    Element { atomic_number: 115, symbol: "Mc", name: "Moscovium", atomic_weight: 290.0, group: Some(15), period: 7, block: Block::P, category: Category::Unknown },
+    Element { atomic_number: 116, symbol: "Lv", name: "Livermorium", atomic_weight: 293.0, group: Some(16), period: 7, block: Block::P, category: Category::Unknown },
+    Element { atomic_number: 117, symbol: "Ts", name: "Tennessine", atomic_weight: 294.0, group: Some(17), period: 7, block: Block::P, category: Category::Unknown },
+    Element { atomic_number: 118, symbol: "Og", name: "Oganesson", atomic_weight: 294.0, group: Some(18), period: 7, block: Block::P, category: Category::Unknown },
+];
+
+// Properties of every known element, in the same order as ELEMENTS
+pub static PROPERTIES: [ElementProperties; 118] = [
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: Some(25.0), covalent_radius: Some(31.0), ionic_radius: None, ionization_energies: &[1312.0], electron_affinity: Some(72.769), density: Some(8.988e-05), melting_point: Some(13.99), boiling_point: Some(20.271), oxidation_states: &[1, -1] }, // H
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: Some(28.0), ionic_radius: None, ionization_energies: &[2372.3, 5250.5], electron_affinity: None, density: Some(0.0001785), melting_point: None, boiling_point: Some(4.222), oxidation_states: &[] }, // He
+    ElementProperties { electronegativity: Some(0.98), atomic_radius: Some(145.0), covalent_radius: Some(128.0), ionic_radius: Some((1, 76.0)), ionization_energies: &[520.2, 7298.1, 11815.0], electron_affinity: Some(59.633), density: Some(0.534), melting_point: Some(453.65), boiling_point: Some(1603.0), oxidation_states: &[1] }, // Li
+    ElementProperties { electronegativity: Some(1.57), atomic_radius: Some(105.0), covalent_radius: Some(96.0), ionic_radius: Some((2, 45.0)), ionization_energies: &[899.5, 1757.1, 14848.7, 21006.6], electron_affinity: None, density: Some(1.85), melting_point: Some(1560.0), boiling_point: Some(2742.0), oxidation_states: &[2] }, // Be
+    ElementProperties { electronegativity: Some(2.04), atomic_radius: Some(85.0), covalent_radius: Some(84.0), ionic_radius: Some((3, 27.0)), ionization_energies: &[800.6, 2427.1, 3659.7, 25025.8, 32826.7], electron_affinity: Some(26.989), density: Some(2.34), melting_point: Some(2349.0), boiling_point: Some(4200.0), oxidation_states: &[3] }, // B
+    ElementProperties { electronegativity: Some(2.55), atomic_radius: Some(70.0), covalent_radius: Some(76.0), ionic_radius: Some((4, 16.0)), ionization_energies: &[1086.5, 2352.6, 4620.5, 6222.7, 37831.0, 47277.0], electron_affinity: Some(121.776), density: Some(2.267), melting_point: None, boiling_point: None, oxidation_states: &[-4, 2, 4] }, // C
+    ElementProperties { electronegativity: Some(3.04), atomic_radius: Some(65.0), covalent_radius: Some(71.0), ionic_radius: None, ionization_energies: &[1402.3, 2856.0, 4578.1, 7475.0, 9444.9, 53266.6, 64360.0], electron_affinity: None, density: Some(0.0012506), melting_point: Some(63.15), boiling_point: Some(77.355), oxidation_states: &[-3, 3, 5] }, // N
+    ElementProperties { electronegativity: Some(3.44), atomic_radius: Some(60.0), covalent_radius: Some(66.0), ionic_radius: Some((-2, 140.0)), ionization_energies: &[1313.9, 3388.3, 5300.5, 7469.2, 10989.5, 13326.5, 71330.0, 84078.0], electron_affinity: Some(141.004), density: Some(0.001429), melting_point: Some(54.36), boiling_point: Some(90.188), oxidation_states: &[-2] }, // O
+    ElementProperties { electronegativity: Some(3.98), atomic_radius: Some(50.0), covalent_radius: Some(57.0), ionic_radius: Some((-1, 133.0)), ionization_energies: &[1681.0, 3374.2, 6050.4, 8407.7, 11022.7, 15164.1, 17868.0, 92038.1, 106434.3], electron_affinity: Some(328.165), density: Some(0.001696), melting_point: Some(53.48), boiling_point: Some(85.03), oxidation_states: &[-1] }, // F
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: Some(58.0), ionic_radius: None, ionization_energies: &[2080.7, 3952.3, 6122.0, 9371.0, 12177.0, 15238.0, 19999.0, 23069.5, 115379.5, 131432.0], electron_affinity: None, density: Some(0.0009002), melting_point: Some(24.56), boiling_point: Some(27.104), oxidation_states: &[] }, // Ne
+    ElementProperties { electronegativity: Some(0.93), atomic_radius: Some(180.0), covalent_radius: Some(166.0), ionic_radius: Some((1, 102.0)), ionization_energies: &[495.8, 4562.0, 6910.3, 9543.0], electron_affinity: Some(52.867), density: Some(0.968), melting_point: Some(370.944), boiling_point: Some(1156.09), oxidation_states: &[1] }, // Na
+    ElementProperties { electronegativity: Some(1.31), atomic_radius: Some(150.0), covalent_radius: Some(141.0), ionic_radius: Some((2, 72.0)), ionization_energies: &[737.7, 1450.7, 7732.7, 10542.5], electron_affinity: None, density: Some(1.738), melting_point: Some(923.0), boiling_point: Some(1363.0), oxidation_states: &[2] }, // Mg
+    ElementProperties { electronegativity: Some(1.61), atomic_radius: Some(125.0), covalent_radius: Some(121.0), ionic_radius: Some((3, 53.5)), ionization_energies: &[577.5, 1816.7, 2744.8, 11577.0], electron_affinity: Some(41.763), density: Some(2.7), melting_point: Some(933.47), boiling_point: Some(2743.0), oxidation_states: &[3] }, // Al
+    ElementProperties { electronegativity: Some(1.9), atomic_radius: Some(110.0), covalent_radius: Some(111.0), ionic_radius: Some((4, 40.0)), ionization_energies: &[786.5, 1577.1, 3231.6, 4355.5], electron_affinity: Some(134.068), density: Some(2.329), melting_point: Some(1687.0), boiling_point: Some(3538.0), oxidation_states: &[-4, 4] }, // Si
+    ElementProperties { electronegativity: Some(2.19), atomic_radius: Some(100.0), covalent_radius: Some(107.0), ionic_radius: Some((5, 38.0)), ionization_energies: &[1011.8, 1907.0, 2914.1, 4963.6], electron_affinity: Some(72.037), density: Some(1.823), melting_point: Some(317.3), boiling_point: Some(553.7), oxidation_states: &[-3, 3, 5] }, // P
+    ElementProperties { electronegativity: Some(2.58), atomic_radius: Some(100.0), covalent_radius: Some(105.0), ionic_radius: Some((-2, 184.0)), ionization_energies: &[999.6, 2252.0, 3357.0, 4556.0], electron_affinity: Some(200.41), density: Some(2.07), melting_point: Some(388.36), boiling_point: Some(717.8), oxidation_states: &[-2, 2, 4, 6] }, // S
+    ElementProperties { electronegativity: Some(3.16), atomic_radius: Some(100.0), covalent_radius: Some(102.0), ionic_radius: Some((-1, 181.0)), ionization_energies: &[1251.2, 2298.0, 3822.0, 5158.6], electron_affinity: Some(348.575), density: Some(0.0032), melting_point: Some(171.6), boiling_point: Some(239.11), oxidation_states: &[-1, 1, 3, 5, 7] }, // Cl
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: Some(106.0), ionic_radius: None, ionization_energies: &[1520.6, 2665.8, 3931.0, 5771.0], electron_affinity: None, density: Some(0.001784), melting_point: Some(83.81), boiling_point: Some(87.302), oxidation_states: &[] }, // Ar
+    ElementProperties { electronegativity: Some(0.82), atomic_radius: Some(220.0), covalent_radius: Some(203.0), ionic_radius: Some((1, 138.0)), ionization_energies: &[418.8, 3052.0, 4420.0], electron_affinity: Some(48.383), density: Some(0.89), melting_point: Some(336.7), boiling_point: Some(1032.0), oxidation_states: &[1] }, // K
+    ElementProperties { electronegativity: Some(1.0), atomic_radius: Some(180.0), covalent_radius: Some(176.0), ionic_radius: Some((2, 100.0)), ionization_energies: &[589.8, 1145.4, 4912.4], electron_affinity: Some(2.37), density: Some(1.55), melting_point: Some(1115.0), boiling_point: Some(1757.0), oxidation_states: &[2] }, // Ca
+    ElementProperties { electronegativity: Some(1.36), atomic_radius: Some(160.0), covalent_radius: Some(170.0), ionic_radius: Some((3, 74.5)), ionization_energies: &[633.1, 1235.0, 2388.6], electron_affinity: Some(18.0), density: Some(2.985), melting_point: Some(1814.0), boiling_point: Some(3109.0), oxidation_states: &[3] }, // Sc
+    ElementProperties { electronegativity: Some(1.54), atomic_radius: Some(140.0), covalent_radius: Some(160.0), ionic_radius: Some((4, 60.5)), ionization_energies: &[658.8, 1309.8, 2652.5], electron_affinity: Some(7.289), density: Some(4.506), melting_point: Some(1941.0), boiling_point: Some(3560.0), oxidation_states: &[4] }, // Ti
+    ElementProperties { electronegativity: Some(1.63), atomic_radius: Some(135.0), covalent_radius: Some(153.0), ionic_radius: Some((3, 64.0)), ionization_energies: &[650.9, 1414.0, 2830.0], electron_affinity: Some(50.911), density: Some(6.0), melting_point: Some(2183.0), boiling_point: Some(3680.0), oxidation_states: &[5] }, // V
+    ElementProperties { electronegativity: Some(1.66), atomic_radius: Some(140.0), covalent_radius: Some(139.0), ionic_radius: Some((3, 61.5)), ionization_energies: &[652.9, 1590.6, 2987.0], electron_affinity: Some(65.21), density: Some(7.19), melting_point: Some(2180.0), boiling_point: Some(2944.0), oxidation_states: &[3, 6] }, // Cr
+    ElementProperties { electronegativity: Some(1.55), atomic_radius: Some(140.0), covalent_radius: Some(139.0), ionic_radius: Some((2, 83.0)), ionization_energies: &[717.3, 1509.0, 3248.0], electron_affinity: None, density: Some(7.21), melting_point: Some(1519.0), boiling_point: Some(2334.0), oxidation_states: &[2, 4, 7] }, // Mn
+    ElementProperties { electronegativity: Some(1.83), atomic_radius: Some(140.0), covalent_radius: Some(132.0), ionic_radius: Some((3, 64.5)), ionization_energies: &[762.5, 1561.9, 2957.0], electron_affinity: Some(15.7), density: Some(7.874), melting_point: Some(1811.0), boiling_point: Some(3134.0), oxidation_states: &[2, 3] }, // Fe
+    ElementProperties { electronegativity: Some(1.88), atomic_radius: Some(135.0), covalent_radius: Some(126.0), ionic_radius: Some((2, 74.5)), ionization_energies: &[760.4, 1648.0, 3232.0], electron_affinity: Some(63.9), density: Some(8.9), melting_point: Some(1768.0), boiling_point: Some(3200.0), oxidation_states: &[2, 3] }, // Co
+    ElementProperties { electronegativity: Some(1.91), atomic_radius: Some(135.0), covalent_radius: Some(124.0), ionic_radius: Some((2, 69.0)), ionization_energies: &[737.1, 1753.0, 3395.0], electron_affinity: Some(111.65), density: Some(8.908), melting_point: Some(1728.0), boiling_point: Some(3003.0), oxidation_states: &[2] }, // Ni
+    ElementProperties { electronegativity: Some(1.9), atomic_radius: Some(135.0), covalent_radius: Some(132.0), ionic_radius: Some((2, 73.0)), ionization_energies: &[745.5, 1957.9, 3555.0], electron_affinity: Some(119.235), density: Some(8.96), melting_point: Some(1357.77), boiling_point: Some(2835.0), oxidation_states: &[1, 2] }, // Cu
+    ElementProperties { electronegativity: Some(1.65), atomic_radius: Some(135.0), covalent_radius: Some(122.0), ionic_radius: Some((2, 74.0)), ionization_energies: &[906.4, 1733.3, 3833.0], electron_affinity: None, density: Some(7.14), melting_point: Some(692.68), boiling_point: Some(1180.0), oxidation_states: &[2] }, // Zn
+    ElementProperties { electronegativity: Some(1.81), atomic_radius: Some(130.0), covalent_radius: Some(122.0), ionic_radius: Some((3, 62.0)), ionization_energies: &[578.8, 1979.3, 2963.0], electron_affinity: Some(41.49), density: Some(5.91), melting_point: Some(302.9146), boiling_point: Some(2673.0), oxidation_states: &[3] }, // Ga
+    ElementProperties { electronegativity: Some(2.01), atomic_radius: Some(125.0), covalent_radius: Some(120.0), ionic_radius: Some((4, 53.0)), ionization_energies: &[762.0, 1537.5, 3302.1], electron_affinity: Some(118.935), density: Some(5.323), melting_point: Some(1211.4), boiling_point: Some(3106.0), oxidation_states: &[-4, 2, 4] }, // Ge
+    ElementProperties { electronegativity: Some(2.18), atomic_radius: Some(115.0), covalent_radius: Some(119.0), ionic_radius: Some((3, 58.0)), ionization_energies: &[947.0, 1798.0, 2735.0], electron_affinity: Some(77.65), density: Some(5.727), melting_point: None, boiling_point: None, oxidation_states: &[-3, 3, 5] }, // As
+    ElementProperties { electronegativity: Some(2.55), atomic_radius: Some(115.0), covalent_radius: Some(120.0), ionic_radius: Some((-2, 198.0)), ionization_energies: &[941.0, 2045.0, 2973.7], electron_affinity: Some(194.958), density: Some(4.81), melting_point: Some(494.0), boiling_point: Some(958.0), oxidation_states: &[-2, 2, 4, 6] }, // Se
+    ElementProperties { electronegativity: Some(2.96), atomic_radius: Some(115.0), covalent_radius: Some(120.0), ionic_radius: Some((-1, 196.0)), ionization_energies: &[1139.9, 2103.0, 3470.0], electron_affinity: Some(324.537), density: Some(3.1028), melting_point: Some(265.8), boiling_point: Some(332.0), oxidation_states: &[-1, 1, 3, 5] }, // Br
+    ElementProperties { electronegativity: Some(3.0), atomic_radius: None, covalent_radius: Some(116.0), ionic_radius: None, ionization_energies: &[1350.8, 2350.4, 3565.0], electron_affinity: None, density: Some(0.003749), melting_point: Some(115.78), boiling_point: Some(119.93), oxidation_states: &[2] }, // Kr
+    ElementProperties { electronegativity: Some(0.82), atomic_radius: Some(235.0), covalent_radius: Some(220.0), ionic_radius: Some((1, 152.0)), ionization_energies: &[403.0], electron_affinity: Some(46.884), density: Some(1.532), melting_point: Some(312.45), boiling_point: Some(961.0), oxidation_states: &[1] }, // Rb
+    ElementProperties { electronegativity: Some(0.95), atomic_radius: Some(200.0), covalent_radius: Some(195.0), ionic_radius: Some((2, 118.0)), ionization_energies: &[549.5], electron_affinity: Some(5.023), density: Some(2.64), melting_point: Some(1050.0), boiling_point: Some(1650.0), oxidation_states: &[2] }, // Sr
+    ElementProperties { electronegativity: Some(1.22), atomic_radius: Some(180.0), covalent_radius: Some(190.0), ionic_radius: Some((3, 90.0)), ionization_energies: &[600.0], electron_affinity: Some(29.6), density: Some(4.472), melting_point: Some(1799.0), boiling_point: Some(3203.0), oxidation_states: &[3] }, // Y
+    ElementProperties { electronegativity: Some(1.33), atomic_radius: Some(155.0), covalent_radius: Some(175.0), ionic_radius: Some((4, 72.0)), ionization_energies: &[640.1], electron_affinity: Some(41.806), density: Some(6.52), melting_point: Some(2128.0), boiling_point: Some(4650.0), oxidation_states: &[4] }, // Zr
+    ElementProperties { electronegativity: Some(1.6), atomic_radius: Some(145.0), covalent_radius: Some(164.0), ionic_radius: Some((5, 64.0)), ionization_energies: &[652.1], electron_affinity: Some(88.516), density: Some(8.57), melting_point: Some(2750.0), boiling_point: Some(5017.0), oxidation_states: &[5] }, // Nb
+    ElementProperties { electronegativity: Some(2.16), atomic_radius: Some(145.0), covalent_radius: Some(154.0), ionic_radius: Some((6, 59.0)), ionization_energies: &[684.3], electron_affinity: Some(72.1), density: Some(10.28), melting_point: Some(2896.0), boiling_point: Some(4912.0), oxidation_states: &[4, 6] }, // Mo
+    ElementProperties { electronegativity: Some(1.9), atomic_radius: Some(135.0), covalent_radius: Some(147.0), ionic_radius: Some((4, 64.5)), ionization_energies: &[702.0], electron_affinity: Some(53.0), density: Some(11.0), melting_point: Some(2430.0), boiling_point: Some(4538.0), oxidation_states: &[4, 7] }, // Tc
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: Some(130.0), covalent_radius: Some(146.0), ionic_radius: Some((3, 68.0)), ionization_energies: &[710.2], electron_affinity: Some(100.96), density: Some(12.45), melting_point: Some(2607.0), boiling_point: Some(4423.0), oxidation_states: &[3, 4] }, // Ru
+    ElementProperties { electronegativity: Some(2.28), atomic_radius: Some(135.0), covalent_radius: Some(142.0), ionic_radius: Some((3, 66.5)), ionization_energies: &[719.7], electron_affinity: Some(110.27), density: Some(12.41), melting_point: Some(2237.0), boiling_point: Some(3968.0), oxidation_states: &[3] }, // Rh
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: Some(140.0), covalent_radius: Some(139.0), ionic_radius: Some((2, 86.0)), ionization_energies: &[804.4], electron_affinity: Some(54.24), density: Some(12.023), melting_point: Some(1828.05), boiling_point: Some(3236.0), oxidation_states: &[2, 4] }, // Pd
+    ElementProperties { electronegativity: Some(1.93), atomic_radius: Some(160.0), covalent_radius: Some(145.0), ionic_radius: Some((1, 115.0)), ionization_energies: &[731.0], electron_affinity: Some(125.86), density: Some(10.49), melting_point: Some(1234.93), boiling_point: Some(2435.0), oxidation_states: &[1] }, // Ag
+    ElementProperties { electronegativity: Some(1.69), atomic_radius: Some(155.0), covalent_radius: Some(144.0), ionic_radius: Some((2, 95.0)), ionization_energies: &[867.8], electron_affinity: None, density: Some(8.65), melting_point: Some(594.22), boiling_point: Some(1040.0), oxidation_states: &[2] }, // Cd
+    ElementProperties { electronegativity: Some(1.78), atomic_radius: Some(155.0), covalent_radius: Some(142.0), ionic_radius: Some((3, 80.0)), ionization_energies: &[558.3], electron_affinity: Some(37.04), density: Some(7.31), melting_point: Some(429.75), boiling_point: Some(2345.0), oxidation_states: &[3] }, // In
+    ElementProperties { electronegativity: Some(1.96), atomic_radius: Some(145.0), covalent_radius: Some(139.0), ionic_radius: Some((4, 69.0)), ionization_energies: &[708.6], electron_affinity: Some(107.298), density: Some(7.265), melting_point: Some(505.08), boiling_point: Some(2875.0), oxidation_states: &[-4, 2, 4] }, // Sn
+    ElementProperties { electronegativity: Some(2.05), atomic_radius: Some(145.0), covalent_radius: Some(139.0), ionic_radius: Some((3, 76.0)), ionization_energies: &[834.0], electron_affinity: Some(101.059), density: Some(6.697), melting_point: Some(903.78), boiling_point: Some(1908.0), oxidation_states: &[-3, 3, 5] }, // Sb
+    ElementProperties { electronegativity: Some(2.1), atomic_radius: Some(140.0), covalent_radius: Some(138.0), ionic_radius: Some((-2, 221.0)), ionization_energies: &[869.3], electron_affinity: Some(190.161), density: Some(6.24), melting_point: Some(722.66), boiling_point: Some(1261.0), oxidation_states: &[-2, 2, 4, 6] }, // Te
+    ElementProperties { electronegativity: Some(2.66), atomic_radius: Some(140.0), covalent_radius: Some(139.0), ionic_radius: Some((-1, 220.0)), ionization_energies: &[1008.4], electron_affinity: Some(295.153), density: Some(4.933), melting_point: Some(386.85), boiling_point: Some(457.4), oxidation_states: &[-1, 1, 3, 5, 7] }, // I
+    ElementProperties { electronegativity: Some(2.6), atomic_radius: None, covalent_radius: Some(140.0), ionic_radius: None, ionization_energies: &[1170.4], electron_affinity: None, density: Some(0.005894), melting_point: Some(161.4), boiling_point: Some(165.051), oxidation_states: &[2, 4, 6] }, // Xe
+    ElementProperties { electronegativity: Some(0.79), atomic_radius: Some(260.0), covalent_radius: Some(244.0), ionic_radius: Some((1, 167.0)), ionization_energies: &[375.7], electron_affinity: Some(45.505), density: Some(1.93), melting_point: Some(301.7), boiling_point: Some(944.0), oxidation_states: &[1] }, // Cs
+    ElementProperties { electronegativity: Some(0.89), atomic_radius: Some(215.0), covalent_radius: Some(215.0), ionic_radius: Some((2, 135.0)), ionization_energies: &[502.9], electron_affinity: Some(13.954), density: Some(3.51), melting_point: Some(1000.0), boiling_point: Some(2118.0), oxidation_states: &[2] }, // Ba
+    ElementProperties { electronegativity: Some(1.1), atomic_radius: Some(195.0), covalent_radius: Some(207.0), ionic_radius: Some((3, 103.2)), ionization_energies: &[538.1], electron_affinity: Some(53.79), density: Some(6.162), melting_point: Some(1193.0), boiling_point: Some(3737.0), oxidation_states: &[3] }, // La
+    ElementProperties { electronegativity: Some(1.12), atomic_radius: Some(185.0), covalent_radius: Some(204.0), ionic_radius: Some((3, 101.0)), ionization_energies: &[534.4], electron_affinity: Some(55.0), density: Some(6.77), melting_point: Some(1068.0), boiling_point: Some(3716.0), oxidation_states: &[3, 4] }, // Ce
+    ElementProperties { electronegativity: Some(1.13), atomic_radius: Some(185.0), covalent_radius: Some(203.0), ionic_radius: Some((3, 99.0)), ionization_energies: &[527.0], electron_affinity: Some(10.539), density: Some(6.77), melting_point: Some(1208.0), boiling_point: Some(3403.0), oxidation_states: &[3] }, // Pr
+    ElementProperties { electronegativity: Some(1.14), atomic_radius: Some(185.0), covalent_radius: Some(201.0), ionic_radius: Some((3, 98.3)), ionization_energies: &[533.1], electron_affinity: Some(9.406), density: Some(7.01), melting_point: Some(1297.0), boiling_point: Some(3347.0), oxidation_states: &[3] }, // Nd
+    ElementProperties { electronegativity: Some(1.13), atomic_radius: Some(185.0), covalent_radius: Some(199.0), ionic_radius: Some((3, 97.0)), ionization_energies: &[540.0], electron_affinity: Some(12.45), density: Some(7.26), melting_point: Some(1315.0), boiling_point: Some(3273.0), oxidation_states: &[3] }, // Pm
+    ElementProperties { electronegativity: Some(1.17), atomic_radius: Some(185.0), covalent_radius: Some(198.0), ionic_radius: Some((3, 95.8)), ionization_energies: &[544.5], electron_affinity: Some(15.63), density: Some(7.52), melting_point: Some(1345.0), boiling_point: Some(2173.0), oxidation_states: &[3] }, // Sm
+    ElementProperties { electronegativity: Some(1.2), atomic_radius: Some(185.0), covalent_radius: Some(198.0), ionic_radius: Some((3, 94.7)), ionization_energies: &[547.1], electron_affinity: Some(11.2), density: Some(5.264), melting_point: Some(1099.0), boiling_point: Some(1802.0), oxidation_states: &[2, 3] }, // Eu
+    ElementProperties { electronegativity: Some(1.2), atomic_radius: Some(180.0), covalent_radius: Some(196.0), ionic_radius: Some((3, 93.8)), ionization_energies: &[593.4], electron_affinity: Some(13.22), density: Some(7.9), melting_point: Some(1585.0), boiling_point: Some(3546.0), oxidation_states: &[3] }, // Gd
+    ElementProperties { electronegativity: Some(1.1), atomic_radius: Some(175.0), covalent_radius: Some(194.0), ionic_radius: Some((3, 92.3)), ionization_energies: &[565.8], electron_affinity: Some(12.67), density: Some(8.23), melting_point: Some(1629.0), boiling_point: Some(3396.0), oxidation_states: &[3] }, // Tb
+    ElementProperties { electronegativity: Some(1.22), atomic_radius: Some(175.0), covalent_radius: Some(192.0), ionic_radius: Some((3, 91.2)), ionization_energies: &[573.0], electron_affinity: Some(33.96), density: Some(8.54), melting_point: Some(1680.0), boiling_point: Some(2840.0), oxidation_states: &[3] }, // Dy
+    ElementProperties { electronegativity: Some(1.23), atomic_radius: Some(175.0), covalent_radius: Some(192.0), ionic_radius: Some((3, 90.1)), ionization_energies: &[581.0], electron_affinity: Some(32.61), density: Some(8.79), melting_point: Some(1734.0), boiling_point: Some(2873.0), oxidation_states: &[3] }, // Ho
+    ElementProperties { electronegativity: Some(1.24), atomic_radius: Some(175.0), covalent_radius: Some(189.0), ionic_radius: Some((3, 89.0)), ionization_energies: &[589.3], electron_affinity: Some(30.1), density: Some(9.066), melting_point: Some(1802.0), boiling_point: Some(3141.0), oxidation_states: &[3] }, // Er
+    ElementProperties { electronegativity: Some(1.25), atomic_radius: Some(175.0), covalent_radius: Some(190.0), ionic_radius: Some((3, 88.0)), ionization_energies: &[596.7], electron_affinity: Some(99.0), density: Some(9.32), melting_point: Some(1818.0), boiling_point: Some(2223.0), oxidation_states: &[3] }, // Tm
+    ElementProperties { electronegativity: Some(1.1), atomic_radius: Some(175.0), covalent_radius: Some(187.0), ionic_radius: Some((3, 86.8)), ionization_energies: &[603.4], electron_affinity: None, density: Some(6.9), melting_point: Some(1097.0), boiling_point: Some(1469.0), oxidation_states: &[3] }, // Yb
+    ElementProperties { electronegativity: Some(1.27), atomic_radius: Some(175.0), covalent_radius: Some(187.0), ionic_radius: Some((3, 86.1)), ionization_energies: &[523.5], electron_affinity: Some(23.04), density: Some(9.841), melting_point: Some(1925.0), boiling_point: Some(3675.0), oxidation_states: &[3] }, // Lu
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: Some(155.0), covalent_radius: Some(175.0), ionic_radius: Some((4, 71.0)), ionization_energies: &[658.5], electron_affinity: Some(17.18), density: Some(13.31), melting_point: Some(2506.0), boiling_point: Some(4876.0), oxidation_states: &[4] }, // Hf
+    ElementProperties { electronegativity: Some(1.5), atomic_radius: Some(145.0), covalent_radius: Some(170.0), ionic_radius: Some((5, 64.0)), ionization_energies: &[761.0], electron_affinity: Some(31.0), density: Some(16.69), melting_point: Some(3290.0), boiling_point: Some(5731.0), oxidation_states: &[5] }, // Ta
+    ElementProperties { electronegativity: Some(2.36), atomic_radius: Some(135.0), covalent_radius: Some(162.0), ionic_radius: Some((6, 60.0)), ionization_energies: &[770.0], electron_affinity: Some(78.76), density: Some(19.25), melting_point: Some(3695.0), boiling_point: Some(6203.0), oxidation_states: &[4, 6] }, // W
+    ElementProperties { electronegativity: Some(1.9), atomic_radius: Some(135.0), covalent_radius: Some(151.0), ionic_radius: Some((4, 63.0)), ionization_energies: &[760.0], electron_affinity: Some(5.827), density: Some(21.02), melting_point: Some(3459.0), boiling_point: Some(5869.0), oxidation_states: &[4] }, // Re
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: Some(130.0), covalent_radius: Some(144.0), ionic_radius: Some((4, 63.0)), ionization_energies: &[840.0], electron_affinity: Some(103.99), density: Some(22.59), melting_point: Some(3306.0), boiling_point: Some(5285.0), oxidation_states: &[4] }, // Os
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: Some(135.0), covalent_radius: Some(141.0), ionic_radius: Some((4, 62.5)), ionization_energies: &[880.0], electron_affinity: Some(150.91), density: Some(22.56), melting_point: Some(2719.0), boiling_point: Some(4403.0), oxidation_states: &[3, 4] }, // Ir
+    ElementProperties { electronegativity: Some(2.28), atomic_radius: Some(135.0), covalent_radius: Some(136.0), ionic_radius: Some((2, 80.0)), ionization_energies: &[870.0], electron_affinity: Some(205.04), density: Some(21.45), melting_point: Some(2041.4), boiling_point: Some(4098.0), oxidation_states: &[2, 4] }, // Pt
+    ElementProperties { electronegativity: Some(2.54), atomic_radius: Some(135.0), covalent_radius: Some(136.0), ionic_radius: Some((1, 137.0)), ionization_energies: &[890.1], electron_affinity: Some(222.75), density: Some(19.3), melting_point: Some(1337.33), boiling_point: Some(3243.0), oxidation_states: &[3] }, // Au
+    ElementProperties { electronegativity: Some(2.0), atomic_radius: Some(150.0), covalent_radius: Some(132.0), ionic_radius: Some((2, 102.0)), ionization_energies: &[1007.1], electron_affinity: None, density: Some(13.534), melting_point: Some(234.32), boiling_point: Some(629.88), oxidation_states: &[1, 2] }, // Hg
+    ElementProperties { electronegativity: Some(1.62), atomic_radius: Some(190.0), covalent_radius: Some(145.0), ionic_radius: Some((1, 150.0)), ionization_energies: &[589.4], electron_affinity: Some(30.88), density: Some(11.85), melting_point: Some(577.0), boiling_point: Some(1746.0), oxidation_states: &[1, 3] }, // Tl
+    ElementProperties { electronegativity: Some(2.33), atomic_radius: Some(180.0), covalent_radius: Some(146.0), ionic_radius: Some((2, 119.0)), ionization_energies: &[715.6], electron_affinity: Some(34.42), density: Some(11.34), melting_point: Some(600.61), boiling_point: Some(2022.0), oxidation_states: &[2, 4] }, // Pb
+    ElementProperties { electronegativity: Some(2.02), atomic_radius: Some(160.0), covalent_radius: Some(148.0), ionic_radius: Some((3, 103.0)), ionization_energies: &[703.0], electron_affinity: Some(90.92), density: Some(9.78), melting_point: Some(544.7), boiling_point: Some(1837.0), oxidation_states: &[3] }, // Bi
+    ElementProperties { electronegativity: Some(2.0), atomic_radius: Some(190.0), covalent_radius: Some(140.0), ionic_radius: Some((4, 94.0)), ionization_energies: &[812.1], electron_affinity: Some(136.0), density: Some(9.196), melting_point: Some(527.0), boiling_point: Some(1235.0), oxidation_states: &[-2, 2, 4] }, // Po
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: None, covalent_radius: Some(150.0), ionic_radius: None, ionization_energies: &[899.0], electron_affinity: Some(233.087), density: None, melting_point: Some(575.0), boiling_point: None, oxidation_states: &[-1, 1] }, // At
+    ElementProperties { electronegativity: Some(2.2), atomic_radius: None, covalent_radius: Some(150.0), ionic_radius: None, ionization_energies: &[1037.0], electron_affinity: None, density: Some(0.00973), melting_point: Some(202.0), boiling_point: Some(211.5), oxidation_states: &[2] }, // Rn
+    ElementProperties { electronegativity: Some(0.79), atomic_radius: None, covalent_radius: Some(260.0), ionic_radius: Some((1, 180.0)), ionization_energies: &[393.0], electron_affinity: Some(46.89), density: None, melting_point: Some(300.0), boiling_point: None, oxidation_states: &[1] }, // Fr
+    ElementProperties { electronegativity: Some(0.9), atomic_radius: Some(215.0), covalent_radius: Some(221.0), ionic_radius: None, ionization_energies: &[509.3], electron_affinity: Some(9.64), density: Some(5.5), melting_point: Some(973.0), boiling_point: Some(2010.0), oxidation_states: &[2] }, // Ra
+    ElementProperties { electronegativity: Some(1.1), atomic_radius: Some(195.0), covalent_radius: Some(215.0), ionic_radius: Some((3, 112.0)), ionization_energies: &[499.0], electron_affinity: Some(33.77), density: Some(10.0), melting_point: Some(1323.0), boiling_point: Some(3471.0), oxidation_states: &[3] }, // Ac
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: Some(180.0), covalent_radius: Some(206.0), ionic_radius: Some((4, 94.0)), ionization_energies: &[587.0], electron_affinity: Some(112.72), density: Some(11.7), melting_point: Some(2115.0), boiling_point: Some(5061.0), oxidation_states: &[4] }, // Th
+    ElementProperties { electronegativity: Some(1.5), atomic_radius: Some(180.0), covalent_radius: Some(200.0), ionic_radius: Some((5, 78.0)), ionization_energies: &[568.0], electron_affinity: Some(53.0), density: Some(15.37), melting_point: Some(1841.0), boiling_point: Some(4300.0), oxidation_states: &[5] }, // Pa
+    ElementProperties { electronegativity: Some(1.38), atomic_radius: Some(175.0), covalent_radius: Some(196.0), ionic_radius: Some((6, 73.0)), ionization_energies: &[597.6], electron_affinity: Some(30.39), density: Some(19.1), melting_point: Some(1405.3), boiling_point: Some(4404.0), oxidation_states: &[6] }, // U
+    ElementProperties { electronegativity: Some(1.36), atomic_radius: Some(175.0), covalent_radius: Some(190.0), ionic_radius: Some((5, 75.0)), ionization_energies: &[604.5], electron_affinity: Some(45.85), density: Some(20.45), melting_point: Some(912.0), boiling_point: Some(4447.0), oxidation_states: &[5] }, // Np
+    ElementProperties { electronegativity: Some(1.28), atomic_radius: Some(175.0), covalent_radius: Some(187.0), ionic_radius: Some((4, 86.0)), ionization_energies: &[584.7], electron_affinity: None, density: Some(19.85), melting_point: Some(912.5), boiling_point: Some(3505.0), oxidation_states: &[4] }, // Pu
+    ElementProperties { electronegativity: Some(1.13), atomic_radius: Some(175.0), covalent_radius: Some(180.0), ionic_radius: Some((3, 97.5)), ionization_energies: &[578.0], electron_affinity: None, density: Some(12.0), melting_point: Some(1449.0), boiling_point: Some(2880.0), oxidation_states: &[3] }, // Am
+    ElementProperties { electronegativity: Some(1.28), atomic_radius: None, covalent_radius: Some(169.0), ionic_radius: Some((3, 97.0)), ionization_energies: &[581.0], electron_affinity: None, density: Some(13.51), melting_point: Some(1613.0), boiling_point: Some(3383.0), oxidation_states: &[3] }, // Cm
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[601.0], electron_affinity: None, density: Some(14.78), melting_point: Some(1259.0), boiling_point: Some(2900.0), oxidation_states: &[3] }, // Bk
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[608.0], electron_affinity: None, density: Some(15.1), melting_point: Some(1173.0), boiling_point: Some(1743.0), oxidation_states: &[3] }, // Cf
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[619.0], electron_affinity: None, density: Some(8.84), melting_point: Some(1133.0), boiling_point: Some(1269.0), oxidation_states: &[3] }, // Es
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[627.0], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[3] }, // Fm
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[635.0], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[3] }, // Md
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[642.0], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[2] }, // No
+    ElementProperties { electronegativity: Some(1.3), atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[470.0], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[3] }, // Lr
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[4] }, // Rf
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[5] }, // Db
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[6] }, // Sg
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[7] }, // Bh
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[8] }, // Hs
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Mt
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Ds
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Rg
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Cn
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Nh
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Fl
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Mc
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Lv
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Ts
+    ElementProperties { electronegativity: None, atomic_radius: None, covalent_radius: None, ionic_radius: None, ionization_energies: &[], electron_affinity: None, density: None, melting_point: None, boiling_point: None, oxidation_states: &[] }, // Og
+];
+
+// A single nuclide of an element
+// Masses are in unified atomic mass units (u), and abundances are mole fractions of the natural element
+pub struct Isotope {
+    pub atomic_number: u32,
+    pub mass_number: u32,
+    pub mass: f64,
+    pub abundance: f64,
+    // Half-life in years, or None if the isotope is stable
+    pub half_life: Option<f64>,
+}
+
+impl Isotope {
+    pub fn is_stable(&self) -> bool {
+        self.half_life.is_none()
+    }
+
+    pub fn element(&self) -> &'static Element {
+        Element::from_atomic_number(self.atomic_number).expect("Isotope should belong to a known element")
+    }
+}
+
+impl Display for Isotope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        // Rounds away floating point noise, then drops the trailing zeros
+        let percent = format!("{:.6}", self.abundance * 100.0);
+        let percent = percent.trim_end_matches('0').trim_end_matches('.');
+
+        write!(f, "{}-{}: {} u, {}% natural abundance", self.element().symbol, self.mass_number, self.mass, percent)?;
+
+        match self.half_life {
+            // Show short half-lives in days, to avoid tiny fractions of a year
+            Some(years) if years < 1.0 => write!(f, ", radioactive (half-life {:.4} days)", years * 365.25),
+            Some(years) => write!(f, ", radioactive (half-life {:e} years)", years),
+            None => write!(f, ", stable"),
+        }
+    }
+}
+
+pub const ISOTOPE_SOURCE: &str = "NIST Atomic Weights and Isotopic Compositions, with half-lives from NUBASE2020";
+
+// Naturally occurring isotopes of every element with a stable or primordial isotope, plus notable radioactive isotopes
+// Primordial isotopes with extremely long half-lives (e.g. Ca-48) are listed as radioactive
+pub static ISOTOPES: [Isotope; 303] = [
+    Isotope { atomic_number: 1, mass_number: 1, mass: 1.00782503207, abundance: 0.999885, half_life: None }, // H-1
+    Isotope { atomic_number: 1, mass_number: 2, mass: 2.0141017778, abundance: 0.000115, half_life: None }, // H-2
+    Isotope { atomic_number: 1, mass_number: 3, mass: 3.0160492777, abundance: 0.0, half_life: Some(12.32) }, // H-3
+    Isotope { atomic_number: 2, mass_number: 3, mass: 3.0160293191, abundance: 1.34e-06, half_life: None }, // He-3
+    Isotope { atomic_number: 2, mass_number: 4, mass: 4.00260325415, abundance: 0.99999866, half_life: None }, // He-4
+    Isotope { atomic_number: 3, mass_number: 6, mass: 6.015122795, abundance: 0.0759, half_life: None }, // Li-6
+    Isotope { atomic_number: 3, mass_number: 7, mass: 7.01600455, abundance: 0.9241, half_life: None }, // Li-7
+    Isotope { atomic_number: 4, mass_number: 9, mass: 9.0121822, abundance: 1.0, half_life: None }, // Be-9
+    Isotope { atomic_number: 5, mass_number: 10, mass: 10.0129370, abundance: 0.199, half_life: None }, // B-10
+    Isotope { atomic_number: 5, mass_number: 11, mass: 11.0093054, abundance: 0.801, half_life: None }, // B-11
+    Isotope { atomic_number: 6, mass_number: 12, mass: 12.0, abundance: 0.9893, half_life: None }, // C-12
+    Isotope { atomic_number: 6, mass_number: 13, mass: 13.0033548378, abundance: 0.0107, half_life: None }, // C-13
+    Isotope { atomic_number: 6, mass_number: 14, mass: 14.003241989, abundance: 0.0, half_life: Some(5730.0) }, // C-14
+    Isotope { atomic_number: 7, mass_number: 14, mass: 14.0030740048, abundance: 0.99636, half_life: None }, // N-14
+    Isotope { atomic_number: 7, mass_number: 15, mass: 15.0001088982, abundance: 0.00364, half_life: None }, // N-15
+    Isotope { atomic_number: 8, mass_number: 16, mass: 15.99491461956, abundance: 0.99757, half_life: None }, // O-16
+    Isotope { atomic_number: 8, mass_number: 17, mass: 16.99913170, abundance: 0.00038, half_life: None }, // O-17
+    Isotope { atomic_number: 8, mass_number: 18, mass: 17.9991610, abundance: 0.00205, half_life: None }, // O-18
+    Isotope { atomic_number: 9, mass_number: 19, mass: 18.99840322, abundance: 1.0, half_life: None }, // F-19
+    Isotope { atomic_number: 10, mass_number: 20, mass: 19.9924401754, abundance: 0.9048, half_life: None }, // Ne-20
+    Isotope { atomic_number: 10, mass_number: 21, mass: 20.99384668, abundance: 0.0027, half_life: None }, // Ne-21
+    Isotope { atomic_number: 10, mass_number: 22, mass: 21.991385114, abundance: 0.0925, half_life: None }, // Ne-22
+    Isotope { atomic_number: 11, mass_number: 23, mass: 22.9897692809, abundance: 1.0, half_life: None }, // Na-23
+    Isotope { atomic_number: 12, mass_number: 24, mass: 23.985041700, abundance: 0.7899, half_life: None }, // Mg-24
+    Isotope { atomic_number: 12, mass_number: 25, mass: 24.98583692, abundance: 0.1, half_life: None }, // Mg-25
+    Isotope { atomic_number: 12, mass_number: 26, mass: 25.982592929, abundance: 0.1101, half_life: None }, // Mg-26
+    Isotope { atomic_number: 13, mass_number: 27, mass: 26.98153863, abundance: 1.0, half_life: None }, // Al-27
+    Isotope { atomic_number: 14, mass_number: 28, mass: 27.9769265325, abundance: 0.92223, half_life: None }, // Si-28
+    Isotope { atomic_number: 14, mass_number: 29, mass: 28.976494700, abundance: 0.04685, half_life: None }, // Si-29
+    Isotope { atomic_number: 14, mass_number: 30, mass: 29.97377017, abundance: 0.03092, half_life: None }, // Si-30
+    Isotope { atomic_number: 15, mass_number: 31, mass: 30.97376163, abundance: 1.0, half_life: None }, // P-31
+    Isotope { atomic_number: 16, mass_number: 32, mass: 31.97207100, abundance: 0.9499, half_life: None }, // S-32
+    Isotope { atomic_number: 16, mass_number: 33, mass: 32.97145876, abundance: 0.0075, half_life: None }, // S-33
+    Isotope { atomic_number: 16, mass_number: 34, mass: 33.96786690, abundance: 0.0425, half_life: None }, // S-34
+    Isotope { atomic_number: 16, mass_number: 36, mass: 35.96708076, abundance: 0.0001, half_life: None }, // S-36
+    Isotope { atomic_number: 17, mass_number: 35, mass: 34.96885268, abundance: 0.7576, half_life: None }, // Cl-35
+    Isotope { atomic_number: 17, mass_number: 37, mass: 36.96590259, abundance: 0.2424, half_life: None }, // Cl-37
+    Isotope { atomic_number: 18, mass_number: 36, mass: 35.967545106, abundance: 0.003365, half_life: None }, // Ar-36
+    Isotope { atomic_number: 18, mass_number: 38, mass: 37.9627324, abundance: 0.000632, half_life: None }, // Ar-38
+    Isotope { atomic_number: 18, mass_number: 40, mass: 39.9623831225, abundance: 0.996003, half_life: None }, // Ar-40
+    Isotope { atomic_number: 19, mass_number: 39, mass: 38.96370668, abundance: 0.932581, half_life: None }, // K-39
+    Isotope { atomic_number: 19, mass_number: 40, mass: 39.96399848, abundance: 0.000117, half_life: Some(1248000000.0) }, // K-40
+    Isotope { atomic_number: 19, mass_number: 41, mass: 40.96182576, abundance: 0.067302, half_life: None }, // K-41
+    Isotope { atomic_number: 20, mass_number: 40, mass: 39.96259098, abundance: 0.96941, half_life: None }, // Ca-40
+    Isotope { atomic_number: 20, mass_number: 42, mass: 41.95861801, abundance: 0.00647, half_life: None }, // Ca-42
+    Isotope { atomic_number: 20, mass_number: 43, mass: 42.9587666, abundance: 0.00135, half_life: None }, // Ca-43
+    Isotope { atomic_number: 20, mass_number: 44, mass: 43.9554818, abundance: 0.02086, half_life: None }, // Ca-44
+    Isotope { atomic_number: 20, mass_number: 46, mass: 45.9536926, abundance: 4e-05, half_life: None }, // Ca-46
+    Isotope { atomic_number: 20, mass_number: 48, mass: 47.952534, abundance: 0.00187, half_life: Some(5.6e+19) }, // Ca-48
+    Isotope { atomic_number: 21, mass_number: 45, mass: 44.9559119, abundance: 1.0, half_life: None }, // Sc-45
+    Isotope { atomic_number: 22, mass_number: 46, mass: 45.9526316, abundance: 0.0825, half_life: None }, // Ti-46
+    Isotope { atomic_number: 22, mass_number: 47, mass: 46.9517631, abundance: 0.0744, half_life: None }, // Ti-47
+    Isotope { atomic_number: 22, mass_number: 48, mass: 47.9479463, abundance: 0.7372, half_life: None }, // Ti-48
+    Isotope { atomic_number: 22, mass_number: 49, mass: 48.9478700, abundance: 0.0541, half_life: None }, // Ti-49
+    Isotope { atomic_number: 22, mass_number: 50, mass: 49.9447912, abundance: 0.0518, half_life: None }, // Ti-50
+    Isotope { atomic_number: 23, mass_number: 50, mass: 49.9471585, abundance: 0.0025, half_life: Some(2.7e+17) }, // V-50
+    Isotope { atomic_number: 23, mass_number: 51, mass: 50.9439595, abundance: 0.9975, half_life: None }, // V-51
+    Isotope { atomic_number: 24, mass_number: 50, mass: 49.9460442, abundance: 0.04345, half_life: None }, // Cr-50
+    Isotope { atomic_number: 24, mass_number: 52, mass: 51.9405075, abundance: 0.83789, half_life: None }, // Cr-52
+    Isotope { atomic_number: 24, mass_number: 53, mass: 52.9406494, abundance: 0.09501, half_life: None }, // Cr-53
+    Isotope { atomic_number: 24, mass_number: 54, mass: 53.9388804, abundance: 0.02365, half_life: None }, // Cr-54
+    Isotope { atomic_number: 25, mass_number: 55, mass: 54.9380451, abundance: 1.0, half_life: None }, // Mn-55
+    Isotope { atomic_number: 26, mass_number: 54, mass: 53.9396105, abundance: 0.05845, half_life: None }, // Fe-54
+    Isotope { atomic_number: 26, mass_number: 56, mass: 55.9349375, abundance: 0.91754, half_life: None }, // Fe-56
+    Isotope { atomic_number: 26, mass_number: 57, mass: 56.9353940, abundance: 0.02119, half_life: None }, // Fe-57
+    Isotope { atomic_number: 26, mass_number: 58, mass: 57.9332756, abundance: 0.00282, half_life: None }, // Fe-58
+    Isotope { atomic_number: 27, mass_number: 59, mass: 58.9331950, abundance: 1.0, half_life: None }, // Co-59
+    Isotope { atomic_number: 27, mass_number: 60, mass: 59.9338171, abundance: 0.0, half_life: Some(5.2714) }, // Co-60
+    Isotope { atomic_number: 28, mass_number: 58, mass: 57.9353429, abundance: 0.680769, half_life: None }, // Ni-58
+    Isotope { atomic_number: 28, mass_number: 60, mass: 59.9307864, abundance: 0.262231, half_life: None }, // Ni-60
+    Isotope { atomic_number: 28, mass_number: 61, mass: 60.9310560, abundance: 0.011399, half_life: None }, // Ni-61
+    Isotope { atomic_number: 28, mass_number: 62, mass: 61.9283451, abundance: 0.036345, half_life: None }, // Ni-62
+    Isotope { atomic_number: 28, mass_number: 64, mass: 63.9279660, abundance: 0.009256, half_life: None }, // Ni-64
+    Isotope { atomic_number: 29, mass_number: 63, mass: 62.9295975, abundance: 0.6915, half_life: None }, // Cu-63
+    Isotope { atomic_number: 29, mass_number: 65, mass: 64.9277895, abundance: 0.3085, half_life: None }, // Cu-65
+    Isotope { atomic_number: 30, mass_number: 64, mass: 63.9291422, abundance: 0.48268, half_life: None }, // Zn-64
+    Isotope { atomic_number: 30, mass_number: 66, mass: 65.9260334, abundance: 0.27975, half_life: None }, // Zn-66
+    Isotope { atomic_number: 30, mass_number: 67, mass: 66.9271273, abundance: 0.04102, half_life: None }, // Zn-67
+    Isotope { atomic_number: 30, mass_number: 68, mass: 67.9248442, abundance: 0.19024, half_life: None }, // Zn-68
+    Isotope { atomic_number: 30, mass_number: 70, mass: 69.9253193, abundance: 0.00631, half_life: None }, // Zn-70
+    Isotope { atomic_number: 31, mass_number: 69, mass: 68.9255736, abundance: 0.60108, half_life: None }, // Ga-69
+    Isotope { atomic_number: 31, mass_number: 71, mass: 70.9247013, abundance: 0.39892, half_life: None }, // Ga-71
+    Isotope { atomic_number: 32, mass_number: 70, mass: 69.9242474, abundance: 0.2038, half_life: None }, // Ge-70
+    Isotope { atomic_number: 32, mass_number: 72, mass: 71.9220758, abundance: 0.2731, half_life: None }, // Ge-72
+    Isotope { atomic_number: 32, mass_number: 73, mass: 72.9234589, abundance: 0.0776, half_life: None }, // Ge-73
+    Isotope { atomic_number: 32, mass_number: 74, mass: 73.9211778, abundance: 0.3672, half_life: None }, // Ge-74
+    Isotope { atomic_number: 32, mass_number: 76, mass: 75.9214026, abundance: 0.0783, half_life: Some(1.926e+21) }, // Ge-76
+    Isotope { atomic_number: 33, mass_number: 75, mass: 74.9215965, abundance: 1.0, half_life: None }, // As-75
+    Isotope { atomic_number: 34, mass_number: 74, mass: 73.9224764, abundance: 0.0086, half_life: None }, // Se-74
+    Isotope { atomic_number: 34, mass_number: 76, mass: 75.9192136, abundance: 0.0923, half_life: None }, // Se-76
+    Isotope { atomic_number: 34, mass_number: 77, mass: 76.9199140, abundance: 0.076, half_life: None }, // Se-77
+    Isotope { atomic_number: 34, mass_number: 78, mass: 77.9173091, abundance: 0.2369, half_life: None }, // Se-78
+    Isotope { atomic_number: 34, mass_number: 80, mass: 79.9165213, abundance: 0.498, half_life: None }, // Se-80
+    Isotope { atomic_number: 34, mass_number: 82, mass: 81.9166994, abundance: 0.0882, half_life: Some(8.76e+19) }, // Se-82
+    Isotope { atomic_number: 35, mass_number: 79, mass: 78.9183371, abundance: 0.5069, half_life: None }, // Br-79
+    Isotope { atomic_number: 35, mass_number: 81, mass: 80.9162906, abundance: 0.4931, half_life: None }, // Br-81
+    Isotope { atomic_number: 36, mass_number: 78, mass: 77.9203648, abundance: 0.00355, half_life: Some(9.2e+21) }, // Kr-78
+    Isotope { atomic_number: 36, mass_number: 80, mass: 79.9163790, abundance: 0.02286, half_life: None }, // Kr-80
+    Isotope { atomic_number: 36, mass_number: 82, mass: 81.9134836, abundance: 0.11593, half_life: None }, // Kr-82
+    Isotope { atomic_number: 36, mass_number: 83, mass: 82.914136, abundance: 0.115, half_life: None }, // Kr-83
+    Isotope { atomic_number: 36, mass_number: 84, mass: 83.911507, abundance: 0.56987, half_life: None }, // Kr-84
+    Isotope { atomic_number: 36, mass_number: 86, mass: 85.91061073, abundance: 0.17279, half_life: None }, // Kr-86
+    Isotope { atomic_number: 37, mass_number: 85, mass: 84.911789738, abundance: 0.7217, half_life: None }, // Rb-85
+    Isotope { atomic_number: 37, mass_number: 87, mass: 86.909180527, abundance: 0.2783, half_life: Some(49700000000.0) }, // Rb-87
+    Isotope { atomic_number: 38, mass_number: 84, mass: 83.913425, abundance: 0.0056, half_life: None }, // Sr-84
+    Isotope { atomic_number: 38, mass_number: 86, mass: 85.9092602, abundance: 0.0986, half_life: None }, // Sr-86
+    Isotope { atomic_number: 38, mass_number: 87, mass: 86.9088771, abundance: 0.07, half_life: None }, // Sr-87
+    Isotope { atomic_number: 38, mass_number: 88, mass: 87.9056121, abundance: 0.8258, half_life: None }, // Sr-88
+    Isotope { atomic_number: 38, mass_number: 90, mass: 89.907738, abundance: 0.0, half_life: Some(28.79) }, // Sr-90
+    Isotope { atomic_number: 39, mass_number: 89, mass: 88.9058483, abundance: 1.0, half_life: None }, // Y-89
+    Isotope { atomic_number: 40, mass_number: 90, mass: 89.9047044, abundance: 0.5145, half_life: None }, // Zr-90
+    Isotope { atomic_number: 40, mass_number: 91, mass: 90.9056458, abundance: 0.1122, half_life: None }, // Zr-91
+    Isotope { atomic_number: 40, mass_number: 92, mass: 91.9050408, abundance: 0.1715, half_life: None }, // Zr-92
+    Isotope { atomic_number: 40, mass_number: 94, mass: 93.9063152, abundance: 0.1738, half_life: None }, // Zr-94
+    Isotope { atomic_number: 40, mass_number: 96, mass: 95.9082734, abundance: 0.028, half_life: Some(2e+19) }, // Zr-96
+    Isotope { atomic_number: 41, mass_number: 93, mass: 92.9063781, abundance: 1.0, half_life: None }, // Nb-93
+    Isotope { atomic_number: 42, mass_number: 92, mass: 91.906811, abundance: 0.1453, half_life: None }, // Mo-92
+    Isotope { atomic_number: 42, mass_number: 94, mass: 93.9050883, abundance: 0.0915, half_life: None }, // Mo-94
+    Isotope { atomic_number: 42, mass_number: 95, mass: 94.9058421, abundance: 0.1584, half_life: None }, // Mo-95
+    Isotope { atomic_number: 42, mass_number: 96, mass: 95.9046795, abundance: 0.1667, half_life: None }, // Mo-96
+    Isotope { atomic_number: 42, mass_number: 97, mass: 96.9060215, abundance: 0.096, half_life: None }, // Mo-97
+    Isotope { atomic_number: 42, mass_number: 98, mass: 97.9054082, abundance: 0.2439, half_life: None }, // Mo-98
+    Isotope { atomic_number: 42, mass_number: 100, mass: 99.907477, abundance: 0.0982, half_life: Some(7.3e+18) }, // Mo-100
+    Isotope { atomic_number: 43, mass_number: 99, mass: 98.9062547, abundance: 0.0, half_life: Some(211100.0) }, // Tc-99
+    Isotope { atomic_number: 44, mass_number: 96, mass: 95.907598, abundance: 0.0554, half_life: None }, // Ru-96
+    Isotope { atomic_number: 44, mass_number: 98, mass: 97.905287, abundance: 0.0187, half_life: None }, // Ru-98
+    Isotope { atomic_number: 44, mass_number: 99, mass: 98.9059393, abundance: 0.1276, half_life: None }, // Ru-99
+    Isotope { atomic_number: 44, mass_number: 100, mass: 99.9042195, abundance: 0.126, half_life: None }, // Ru-100
+    Isotope { atomic_number: 44, mass_number: 101, mass: 100.9055821, abundance: 0.1706, half_life: None }, // Ru-101
+    Isotope { atomic_number: 44, mass_number: 102, mass: 101.9043493, abundance: 0.3155, half_life: None }, // Ru-102
+    Isotope { atomic_number: 44, mass_number: 104, mass: 103.905433, abundance: 0.1862, half_life: None }, // Ru-104
+    Isotope { atomic_number: 45, mass_number: 103, mass: 102.905504, abundance: 1.0, half_life: None }, // Rh-103
+    Isotope { atomic_number: 46, mass_number: 102, mass: 101.905609, abundance: 0.0102, half_life: None }, // Pd-102
+    Isotope { atomic_number: 46, mass_number: 104, mass: 103.904036, abundance: 0.1114, half_life: None }, // Pd-104
+    Isotope { atomic_number: 46, mass_number: 105, mass: 104.905085, abundance: 0.2233, half_life: None }, // Pd-105
+    Isotope { atomic_number: 46, mass_number: 106, mass: 105.903486, abundance: 0.2733, half_life: None }, // Pd-106
+    Isotope { atomic_number: 46, mass_number: 108, mass: 107.903892, abundance: 0.2646, half_life: None }, // Pd-108
+    Isotope { atomic_number: 46, mass_number: 110, mass: 109.905153, abundance: 0.1172, half_life: None }, // Pd-110
+    Isotope { atomic_number: 47, mass_number: 107, mass: 106.905097, abundance: 0.51839, half_life: None }, // Ag-107
+    Isotope { atomic_number: 47, mass_number: 109, mass: 108.904752, abundance: 0.48161, half_life: None }, // Ag-109
+    Isotope { atomic_number: 48, mass_number: 106, mass: 105.906459, abundance: 0.0125, half_life: None }, // Cd-106
+    Isotope { atomic_number: 48, mass_number: 108, mass: 107.904184, abundance: 0.0089, half_life: None }, // Cd-108
+    Isotope { atomic_number: 48, mass_number: 110, mass: 109.9030021, abundance: 0.1249, half_life: None }, // Cd-110
+    Isotope { atomic_number: 48, mass_number: 111, mass: 110.9041781, abundance: 0.128, half_life: None }, // Cd-111
+    Isotope { atomic_number: 48, mass_number: 112, mass: 111.9027578, abundance: 0.2413, half_life: None }, // Cd-112
+    Isotope { atomic_number: 48, mass_number: 113, mass: 112.9044017, abundance: 0.1222, half_life: Some(8040000000000000.0) }, // Cd-113
+    Isotope { atomic_number: 48, mass_number: 114, mass: 113.9033585, abundance: 0.2873, half_life: None }, // Cd-114
+    Isotope { atomic_number: 48, mass_number: 116, mass: 115.904756, abundance: 0.0749, half_life: Some(3.1e+19) }, // Cd-116
+    Isotope { atomic_number: 49, mass_number: 113, mass: 112.904058, abundance: 0.0429, half_life: None }, // In-113
+    Isotope { atomic_number: 49, mass_number: 115, mass: 114.903878, abundance: 0.9571, half_life: Some(441000000000000.0) }, // In-115
+    Isotope { atomic_number: 50, mass_number: 112, mass: 111.904818, abundance: 0.0097, half_life: None }, // Sn-112
+    Isotope { atomic_number: 50, mass_number: 114, mass: 113.902779, abundance: 0.0066, half_life: None }, // Sn-114
+    Isotope { atomic_number: 50, mass_number: 115, mass: 114.903342, abundance: 0.0034, half_life: None }, // Sn-115
+    Isotope { atomic_number: 50, mass_number: 116, mass: 115.901741, abundance: 0.1454, half_life: None }, // Sn-116
+    Isotope { atomic_number: 50, mass_number: 117, mass: 116.902952, abundance: 0.0768, half_life: None }, // Sn-117
+    Isotope { atomic_number: 50, mass_number: 118, mass: 117.901603, abundance: 0.2422, half_life: None }, // Sn-118
+    Isotope { atomic_number: 50, mass_number: 119, mass: 118.903308, abundance: 0.0859, half_life: None }, // Sn-119
+    Isotope { atomic_number: 50, mass_number: 120, mass: 119.9021947, abundance: 0.3258, half_life: None }, // Sn-120
+    Isotope { atomic_number: 50, mass_number: 122, mass: 121.903439, abundance: 0.0463, half_life: None }, // Sn-122
+    Isotope { atomic_number: 50, mass_number: 124, mass: 123.9052739, abundance: 0.0579, half_life: None }, // Sn-124
+    Isotope { atomic_number: 51, mass_number: 121, mass: 120.9038157, abundance: 0.5721, half_life: None }, // Sb-121
+    Isotope { atomic_number: 51, mass_number: 123, mass: 122.904214, abundance: 0.4279, half_life: None }, // Sb-123
+    Isotope { atomic_number: 52, mass_number: 120, mass: 119.90402, abundance: 0.0009, half_life: None }, // Te-120
+    Isotope { atomic_number: 52, mass_number: 122, mass: 121.9030439, abundance: 0.0255, half_life: None }, // Te-122
+    Isotope { atomic_number: 52, mass_number: 123, mass: 122.90427, abundance: 0.0089, half_life: None }, // Te-123
+    Isotope { atomic_number: 52, mass_number: 124, mass: 123.9028179, abundance: 0.0474, half_life: None }, // Te-124
+    Isotope { atomic_number: 52, mass_number: 125, mass: 124.9044307, abundance: 0.0707, half_life: None }, // Te-125
+    Isotope { atomic_number: 52, mass_number: 126, mass: 125.9033117, abundance: 0.1884, half_life: None }, // Te-126
+    Isotope { atomic_number: 52, mass_number: 128, mass: 127.9044631, abundance: 0.3174, half_life: Some(7.7e+24) }, // Te-128
+    Isotope { atomic_number: 52, mass_number: 130, mass: 129.9062244, abundance: 0.3408, half_life: Some(7.9e+20) }, // Te-130
+    Isotope { atomic_number: 53, mass_number: 127, mass: 126.904473, abundance: 1.0, half_life: None }, // I-127
+    Isotope { atomic_number: 53, mass_number: 131, mass: 130.9061246, abundance: 0.0, half_life: Some(0.02197) }, // I-131
+    Isotope { atomic_number: 54, mass_number: 124, mass: 123.905893, abundance: 0.000952, half_life: Some(1.8e+22) }, // Xe-124
+    Isotope { atomic_number: 54, mass_number: 126, mass: 125.904274, abundance: 0.00089, half_life: None }, // Xe-126
+    Isotope { atomic_number: 54, mass_number: 128, mass: 127.9035313, abundance: 0.019102, half_life: None }, // Xe-128
+    Isotope { atomic_number: 54, mass_number: 129, mass: 128.9047794, abundance: 0.264006, half_life: None }, // Xe-129
+    Isotope { atomic_number: 54, mass_number: 130, mass: 129.903508, abundance: 0.04071, half_life: None }, // Xe-130
+    Isotope { atomic_number: 54, mass_number: 131, mass: 130.9050824, abundance: 0.212324, half_life: None }, // Xe-131
+    Isotope { atomic_number: 54, mass_number: 132, mass: 131.9041535, abundance: 0.269086, half_life: None }, // Xe-132
+    Isotope { atomic_number: 54, mass_number: 134, mass: 133.9053945, abundance: 0.104357, half_life: None }, // Xe-134
+    Isotope { atomic_number: 54, mass_number: 136, mass: 135.907219, abundance: 0.088573, half_life: Some(2.165e+21) }, // Xe-136
+    Isotope { atomic_number: 55, mass_number: 133, mass: 132.905451933, abundance: 1.0, half_life: None }, // Cs-133
+    Isotope { atomic_number: 55, mass_number: 137, mass: 136.9070895, abundance: 0.0, half_life: Some(30.08) }, // Cs-137
+    Isotope { atomic_number: 56, mass_number: 130, mass: 129.9063208, abundance: 0.00106, half_life: None }, // Ba-130
+    Isotope { atomic_number: 56, mass_number: 132, mass: 131.9050613, abundance: 0.00101, half_life: None }, // Ba-132
+    Isotope { atomic_number: 56, mass_number: 134, mass: 133.9045084, abundance: 0.02417, half_life: None }, // Ba-134
+    Isotope { atomic_number: 56, mass_number: 135, mass: 134.9056886, abundance: 0.06592, half_life: None }, // Ba-135
+    Isotope { atomic_number: 56, mass_number: 136, mass: 135.9045759, abundance: 0.07854, half_life: None }, // Ba-136
+    Isotope { atomic_number: 56, mass_number: 137, mass: 136.9058274, abundance: 0.11232, half_life: None }, // Ba-137
+    Isotope { atomic_number: 56, mass_number: 138, mass: 137.9052472, abundance: 0.71698, half_life: None }, // Ba-138
+    Isotope { atomic_number: 57, mass_number: 138, mass: 137.907112, abundance: 0.0008881, half_life: Some(102000000000.0) }, // La-138
+    Isotope { atomic_number: 57, mass_number: 139, mass: 138.9063533, abundance: 0.9991119, half_life: None }, // La-139
+    Isotope { atomic_number: 58, mass_number: 136, mass: 135.907172, abundance: 0.00185, half_life: None }, // Ce-136
+    Isotope { atomic_number: 58, mass_number: 138, mass: 137.905991, abundance: 0.00251, half_life: None }, // Ce-138
+    Isotope { atomic_number: 58, mass_number: 140, mass: 139.9054387, abundance: 0.8845, half_life: None }, // Ce-140
+    Isotope { atomic_number: 58, mass_number: 142, mass: 141.909244, abundance: 0.11114, half_life: None }, // Ce-142
+    Isotope { atomic_number: 59, mass_number: 141, mass: 140.9076528, abundance: 1.0, half_life: None }, // Pr-141
+    Isotope { atomic_number: 60, mass_number: 142, mass: 141.9077233, abundance: 0.27152, half_life: None }, // Nd-142
+    Isotope { atomic_number: 60, mass_number: 143, mass: 142.9098143, abundance: 0.12174, half_life: None }, // Nd-143
+    Isotope { atomic_number: 60, mass_number: 144, mass: 143.9100873, abundance: 0.23798, half_life: Some(2290000000000000.0) }, // Nd-144
+    Isotope { atomic_number: 60, mass_number: 145, mass: 144.9125736, abundance: 0.08293, half_life: None }, // Nd-145
+    Isotope { atomic_number: 60, mass_number: 146, mass: 145.9131169, abundance: 0.17189, half_life: None }, // Nd-146
+    Isotope { atomic_number: 60, mass_number: 148, mass: 147.916893, abundance: 0.05756, half_life: None }, // Nd-148
+    Isotope { atomic_number: 60, mass_number: 150, mass: 149.920891, abundance: 0.05638, half_life: Some(9.1e+18) }, // Nd-150
+    Isotope { atomic_number: 61, mass_number: 145, mass: 144.912749, abundance: 0.0, half_life: Some(17.7) }, // Pm-145
+    Isotope { atomic_number: 61, mass_number: 147, mass: 146.9151385, abundance: 0.0, half_life: Some(2.6234) }, // Pm-147
+    Isotope { atomic_number: 62, mass_number: 144, mass: 143.911999, abundance: 0.0307, half_life: None }, // Sm-144
+    Isotope { atomic_number: 62, mass_number: 147, mass: 146.9148979, abundance: 0.1499, half_life: Some(106000000000.0) }, // Sm-147
+    Isotope { atomic_number: 62, mass_number: 148, mass: 147.9148227, abundance: 0.1124, half_life: Some(7000000000000000.0) }, // Sm-148
+    Isotope { atomic_number: 62, mass_number: 149, mass: 148.9171847, abundance: 0.1382, half_life: None }, // Sm-149
+    Isotope { atomic_number: 62, mass_number: 150, mass: 149.9172755, abundance: 0.0738, half_life: None }, // Sm-150
+    Isotope { atomic_number: 62, mass_number: 152, mass: 151.9197324, abundance: 0.2675, half_life: None }, // Sm-152
+    Isotope { atomic_number: 62, mass_number: 154, mass: 153.9222093, abundance: 0.2275, half_life: None }, // Sm-154
+    Isotope { atomic_number: 63, mass_number: 151, mass: 150.9198502, abundance: 0.4781, half_life: Some(5e+18) }, // Eu-151
+    Isotope { atomic_number: 63, mass_number: 153, mass: 152.9212303, abundance: 0.5219, half_life: None }, // Eu-153
+    Isotope { atomic_number: 64, mass_number: 152, mass: 151.919791, abundance: 0.002, half_life: Some(108000000000000.0) }, // Gd-152
+    Isotope { atomic_number: 64, mass_number: 154, mass: 153.9208656, abundance: 0.0218, half_life: None }, // Gd-154
+    Isotope { atomic_number: 64, mass_number: 155, mass: 154.922622, abundance: 0.148, half_life: None }, // Gd-155
+    Isotope { atomic_number: 64, mass_number: 156, mass: 155.9221227, abundance: 0.2047, half_life: None }, // Gd-156
+    Isotope { atomic_number: 64, mass_number: 157, mass: 156.9239601, abundance: 0.1565, half_life: None }, // Gd-157
+    Isotope { atomic_number: 64, mass_number: 158, mass: 157.9241039, abundance: 0.2484, half_life: None }, // Gd-158
+    Isotope { atomic_number: 64, mass_number: 160, mass: 159.9270541, abundance: 0.2186, half_life: None }, // Gd-160
+    Isotope { atomic_number: 65, mass_number: 159, mass: 158.9253468, abundance: 1.0, half_life: None }, // Tb-159
+    Isotope { atomic_number: 66, mass_number: 156, mass: 155.924283, abundance: 0.00056, half_life: None }, // Dy-156
+    Isotope { atomic_number: 66, mass_number: 158, mass: 157.924409, abundance: 0.00095, half_life: None }, // Dy-158
+    Isotope { atomic_number: 66, mass_number: 160, mass: 159.9251975, abundance: 0.02329, half_life: None }, // Dy-160
+    Isotope { atomic_number: 66, mass_number: 161, mass: 160.9269334, abundance: 0.18889, half_life: None }, // Dy-161
+    Isotope { atomic_number: 66, mass_number: 162, mass: 161.9267984, abundance: 0.25475, half_life: None }, // Dy-162
+    Isotope { atomic_number: 66, mass_number: 163, mass: 162.9287312, abundance: 0.24896, half_life: None }, // Dy-163
+    Isotope { atomic_number: 66, mass_number: 164, mass: 163.9291748, abundance: 0.2826, half_life: None }, // Dy-164
+    Isotope { atomic_number: 67, mass_number: 165, mass: 164.9303221, abundance: 1.0, half_life: None }, // Ho-165
+    Isotope { atomic_number: 68, mass_number: 162, mass: 161.928778, abundance: 0.00139, half_life: None }, // Er-162
+    Isotope { atomic_number: 68, mass_number: 164, mass: 163.9292, abundance: 0.01601, half_life: None }, // Er-164
+    Isotope { atomic_number: 68, mass_number: 166, mass: 165.9302931, abundance: 0.33503, half_life: None }, // Er-166
+    Isotope { atomic_number: 68, mass_number: 167, mass: 166.9320482, abundance: 0.22869, half_life: None }, // Er-167
+    Isotope { atomic_number: 68, mass_number: 168, mass: 167.9323702, abundance: 0.26978, half_life: None }, // Er-168
+    Isotope { atomic_number: 68, mass_number: 170, mass: 169.9354643, abundance: 0.1491, half_life: None }, // Er-170
+    Isotope { atomic_number: 69, mass_number: 169, mass: 168.9342133, abundance: 1.0, half_life: None }, // Tm-169
+    Isotope { atomic_number: 70, mass_number: 168, mass: 167.933897, abundance: 0.00123, half_life: None }, // Yb-168
+    Isotope { atomic_number: 70, mass_number: 170, mass: 169.9347618, abundance: 0.02982, half_life: None }, // Yb-170
+    Isotope { atomic_number: 70, mass_number: 171, mass: 170.9363258, abundance: 0.1409, half_life: None }, // Yb-171
+    Isotope { atomic_number: 70, mass_number: 172, mass: 171.9363815, abundance: 0.2168, half_life: None }, // Yb-172
+    Isotope { atomic_number: 70, mass_number: 173, mass: 172.9382108, abundance: 0.16103, half_life: None }, // Yb-173
+    Isotope { atomic_number: 70, mass_number: 174, mass: 173.9388621, abundance: 0.32026, half_life: None }, // Yb-174
+    Isotope { atomic_number: 70, mass_number: 176, mass: 175.9425717, abundance: 0.12996, half_life: None }, // Yb-176
+    Isotope { atomic_number: 71, mass_number: 175, mass: 174.9407718, abundance: 0.97401, half_life: None }, // Lu-175
+    Isotope { atomic_number: 71, mass_number: 176, mass: 175.9426863, abundance: 0.02599, half_life: Some(37600000000.0) }, // Lu-176
+    Isotope { atomic_number: 72, mass_number: 174, mass: 173.940046, abundance: 0.0016, half_life: Some(2000000000000000.0) }, // Hf-174
+    Isotope { atomic_number: 72, mass_number: 176, mass: 175.9414086, abundance: 0.0526, half_life: None }, // Hf-176
+    Isotope { atomic_number: 72, mass_number: 177, mass: 176.9432207, abundance: 0.186, half_life: None }, // Hf-177
+    Isotope { atomic_number: 72, mass_number: 178, mass: 177.9436988, abundance: 0.2728, half_life: None }, // Hf-178
+    Isotope { atomic_number: 72, mass_number: 179, mass: 178.9458161, abundance: 0.1362, half_life: None }, // Hf-179
+    Isotope { atomic_number: 72, mass_number: 180, mass: 179.94655, abundance: 0.3508, half_life: None }, // Hf-180
+    Isotope { atomic_number: 73, mass_number: 180, mass: 179.9474648, abundance: 0.0001201, half_life: None }, // Ta-180
+    Isotope { atomic_number: 73, mass_number: 181, mass: 180.9479958, abundance: 0.9998799, half_life: None }, // Ta-181
+    Isotope { atomic_number: 74, mass_number: 180, mass: 179.946704, abundance: 0.0012, half_life: Some(1.8e+18) }, // W-180
+    Isotope { atomic_number: 74, mass_number: 182, mass: 181.9482042, abundance: 0.265, half_life: None }, // W-182
+    Isotope { atomic_number: 74, mass_number: 183, mass: 182.950223, abundance: 0.1431, half_life: None }, // W-183
+    Isotope { atomic_number: 74, mass_number: 184, mass: 183.9509312, abundance: 0.3064, half_life: None }, // W-184
+    Isotope { atomic_number: 74, mass_number: 186, mass: 185.9543641, abundance: 0.2843, half_life: None }, // W-186
+    Isotope { atomic_number: 75, mass_number: 185, mass: 184.952955, abundance: 0.374, half_life: None }, // Re-185
+    Isotope { atomic_number: 75, mass_number: 187, mass: 186.9557531, abundance: 0.626, half_life: Some(41200000000.0) }, // Re-187
+    Isotope { atomic_number: 76, mass_number: 184, mass: 183.9524891, abundance: 0.0002, half_life: None }, // Os-184
+    Isotope { atomic_number: 76, mass_number: 186, mass: 185.9538382, abundance: 0.0159, half_life: Some(2000000000000000.0) }, // Os-186
+    Isotope { atomic_number: 76, mass_number: 187, mass: 186.9557505, abundance: 0.0196, half_life: None }, // Os-187
+    Isotope { atomic_number: 76, mass_number: 188, mass: 187.9558382, abundance: 0.1324, half_life: None }, // Os-188
+    Isotope { atomic_number: 76, mass_number: 189, mass: 188.9581475, abundance: 0.1615, half_life: None }, // Os-189
+    Isotope { atomic_number: 76, mass_number: 190, mass: 189.958447, abundance: 0.2626, half_life: None }, // Os-190
+    Isotope { atomic_number: 76, mass_number: 192, mass: 191.9614807, abundance: 0.4078, half_life: None }, // Os-192
+    Isotope { atomic_number: 77, mass_number: 191, mass: 190.960594, abundance: 0.373, half_life: None }, // Ir-191
+    Isotope { atomic_number: 77, mass_number: 193, mass: 192.9629264, abundance: 0.627, half_life: None }, // Ir-193
+    Isotope { atomic_number: 78, mass_number: 190, mass: 189.959932, abundance: 0.00012, half_life: Some(483000000000.0) }, // Pt-190
+    Isotope { atomic_number: 78, mass_number: 192, mass: 191.961038, abundance: 0.00782, half_life: None }, // Pt-192
+    Isotope { atomic_number: 78, mass_number: 194, mass: 193.9626803, abundance: 0.3286, half_life: None }, // Pt-194
+    Isotope { atomic_number: 78, mass_number: 195, mass: 194.9647911, abundance: 0.3378, half_life: None }, // Pt-195
+    Isotope { atomic_number: 78, mass_number: 196, mass: 195.9649515, abundance: 0.2521, half_life: None }, // Pt-196
+    Isotope { atomic_number: 78, mass_number: 198, mass: 197.967893, abundance: 0.07356, half_life: None }, // Pt-198
+    Isotope { atomic_number: 79, mass_number: 197, mass: 196.9665687, abundance: 1.0, half_life: None }, // Au-197
+    Isotope { atomic_number: 80, mass_number: 196, mass: 195.965833, abundance: 0.0015, half_life: None }, // Hg-196
+    Isotope { atomic_number: 80, mass_number: 198, mass: 197.966769, abundance: 0.0997, half_life: None }, // Hg-198
+    Isotope { atomic_number: 80, mass_number: 199, mass: 198.9682799, abundance: 0.1687, half_life: None }, // Hg-199
+    Isotope { atomic_number: 80, mass_number: 200, mass: 199.968326, abundance: 0.231, half_life: None }, // Hg-200
+    Isotope { atomic_number: 80, mass_number: 201, mass: 200.9703023, abundance: 0.1318, half_life: None }, // Hg-201
+    Isotope { atomic_number: 80, mass_number: 202, mass: 201.970643, abundance: 0.2986, half_life: None }, // Hg-202
+    Isotope { atomic_number: 80, mass_number: 204, mass: 203.9734939, abundance: 0.0687, half_life: None }, // Hg-204
+    Isotope { atomic_number: 81, mass_number: 203, mass: 202.9723442, abundance: 0.2952, half_life: None }, // Tl-203
+    Isotope { atomic_number: 81, mass_number: 205, mass: 204.9744275, abundance: 0.7048, half_life: None }, // Tl-205
+    Isotope { atomic_number: 82, mass_number: 204, mass: 203.9730436, abundance: 0.014, half_life: None }, // Pb-204
+    Isotope { atomic_number: 82, mass_number: 206, mass: 205.9744653, abundance: 0.241, half_life: None }, // Pb-206
+    Isotope { atomic_number: 82, mass_number: 207, mass: 206.9758969, abundance: 0.221, half_life: None }, // Pb-207
+    Isotope { atomic_number: 82, mass_number: 208, mass: 207.9766521, abundance: 0.524, half_life: None }, // Pb-208
+    Isotope { atomic_number: 83, mass_number: 209, mass: 208.9803987, abundance: 1.0, half_life: Some(2.01e+19) }, // Bi-209
+    Isotope { atomic_number: 84, mass_number: 210, mass: 209.9828737, abundance: 0.0, half_life: Some(0.37886) }, // Po-210
+    Isotope { atomic_number: 86, mass_number: 222, mass: 222.0175777, abundance: 0.0, half_life: Some(0.010468) }, // Rn-222
+    Isotope { atomic_number: 88, mass_number: 226, mass: 226.0254098, abundance: 0.0, half_life: Some(1600.0) }, // Ra-226
+    Isotope { atomic_number: 90, mass_number: 230, mass: 230.0331338, abundance: 0.0002, half_life: Some(75380.0) }, // Th-230
+    Isotope { atomic_number: 90, mass_number: 232, mass: 232.0380553, abundance: 0.9998, half_life: Some(14000000000.0) }, // Th-232
+    Isotope { atomic_number: 91, mass_number: 231, mass: 231.035884, abundance: 1.0, half_life: Some(32760.0) }, // Pa-231
+    Isotope { atomic_number: 92, mass_number: 234, mass: 234.0409521, abundance: 5.4e-05, half_life: Some(245500.0) }, // U-234
+    Isotope { atomic_number: 92, mass_number: 235, mass: 235.0439299, abundance: 0.007204, half_life: Some(704000000.0) }, // U-235
+    Isotope { atomic_number: 92, mass_number: 238, mass: 238.0507882, abundance: 0.992742, half_life: Some(4468000000.0) }, // U-238
+    Isotope { atomic_number: 94, mass_number: 239, mass: 239.0521634, abundance: 0.0, half_life: Some(24110.0) }, // Pu-239
+    Isotope { atomic_number: 94, mass_number: 244, mass: 244.064204, abundance: 0.0, half_life: Some(80000000.0) }, // Pu-244
+];
 
 // Gets the atomic number of a given atom (e.g. "H" -> 1)
 pub fn atomic_number(element: &str) -> Option<u32> {
-    match element {
-        "H" | "Hydrogen" => Some(1),
-        "He" | "Helium" => Some(2),
-        "Li" | "Lithium" => Some(3),
-        "Be" | "Beryllium" => Some(4),
-        "B" | "Boron" => Some(5),
-        "C" | "Carbon" => Some(6),
-        "N" | "Nitrogen" => Some(7),
-        "O" | "Oxygen" => Some(8),
-        "F" | "Fluorine" => Some(9),
-        "Ne" | "Neon" => Some(10),
-        "Na" | "Sodium" => Some(11),
-        "Mg" | "Magnesium" => Some(12),
-        "Al" | "Aluminum" => Some(13),
-        "Si" | "Silicon" => Some(14),
-        "P" | "Phosphorus" => Some(15),
-        "S" | "Sulfur" => Some(16),
-        "Cl" | "Chlorine" => Some(17),
-        "Ar" | "Argon" => Some(18),
-        "K" | "Potassium" => Some(19),
-        "Ca" | "Calcium" => Some(20),
-        "Sc" | "Scandium" => Some(21),
-        "Ti" | "Titanium" => Some(22),
-        "V" | "Vanadium" => Some(23),
-        "Cr" | "Chromium" => Some(24),
-        "Mn" | "Manganese" => Some(25),
-        "Fe" | "Iron" => Some(26),
-        "Co" | "Cobalt" => Some(27),
-        "Ni" | "Nickel" => Some(28),
-        "Cu" | "Copper" => Some(29),
-        "Zn" | "Zinc" => Some(30),
-        "Ga" | "Gallium" => Some(31),
-        "Ge" | "Germanium" => Some(32),
-        "As" | "Arsenic" => Some(33),
-        "Se" | "Selenium" => Some(34),
-        "Br" | "Bromine" => Some(35),
-        "Kr" | "Krypton" => Some(36),
-        "Rb" | "Rubidium" => Some(37),
-        "Sr" | "Strontium" => Some(38),
-        "Y" | "Yttrium" => Some(39),
-        "Zr" | "Zirconium" => Some(40),
-        "Nb" | "Niobium" => Some(41),
-        "Mo" | "Molybdenum" => Some(42),
-        "Tc" | "Technetium" => Some(43),
-        "Ru" | "Ruthenium" => Some(44),
-        "Rh" | "Rhodium" => Some(45),
-        "Pd" | "Palladium" => Some(46),
-        "Ag" | "Silver" => Some(47),
-        "Cd" | "Cadmium" => Some(48),
-        "In" | "Indium" => Some(49),
-        "Sn" | "Tin" => Some(50),
-        "Sb" | "Antimony" => Some(51),
-        "Te" | "Tellurium" => Some(52),
-        "I" | "Iodine" => Some(53),
-        "Xe" | "Xenon" => Some(54),
-        "Cs" | "Caesium" => Some(55),
-        "Ba" | "Barium" => Some(56),
-        "La" | "Lanthanum" => Some(57),
-        "Ce" | "Cerium" => Some(58),
-        "Pr" | "Praseodymium" => Some(59),
-        "Nd" | "Neodymium" => Some(60),
-        "Pm" | "Promethium" => Some(61),
-        "Sm" | "Samarium" => Some(62),
-        "Eu" | "Europium" => Some(63),
-        "Gd" | "Gadolinium" => Some(64),
-        "Tb" | "Terbium" => Some(65),
-        "Dy" | "Dysprosium" => Some(66),
-        "Ho" | "Holmium" => Some(67),
-        "Er" | "Erbium" => Some(68),
-        "Tm" | "Thulium" => Some(69),
-        "Yb" | "Ytterbium" => Some(70),
-        "Lu" | "Lutetium" => Some(71),
-        "Hf" | "Hafnium" => Some(72),
-        "Ta" | "Tantalum" => Some(73),
-        "W" | "Tungsten" => Some(74),
-        "Re" | "Rhenium" => Some(75),
-        "Os" | "Osmium" => Some(76),
-        "Ir" | "Iridium" => Some(77),
-        "Pt" | "Platinum" => Some(78),
-        "Au" | "Gold" => Some(79),
-        "Hg" | "Mercury" => Some(80),
-        "Tl" | "Thallium" => Some(81),
-        "Pb" | "Lead" => Some(82),
-        "Bi" | "Bismuth" => Some(83),
-        "Po" | "Polonium" => Some(84),
-        "At" | "Astatine" => Some(85),
-        "Rn" | "Radon" => Some(86),
-        "Fr" | "Francium" => Some(87),
-        "Ra" | "Radium" => Some(88),
-        "Ac" | "Actinium" => Some(89),
-        "Th" | "Thorium" => Some(90),
-        "Pa" | "Protactinium" => Some(91),
-        "U" | "Uranium" => Some(92),
-        "Np" | "Neptunium" => Some(93),
-        "Pu" | "Plutonium" => Some(94),
-        "Am" | "Americium" => Some(95),
-        "Cm" | "Curium" => Some(96),
-        "Bk" | "Berkelium" => Some(97),
-        "Cf" | "Californium" => Some(98),
-        "Es" | "Einsteinium" => Some(99),
-        "Fm" | "Fermium" => Some(100),
-        "Md" | "Mendelevium" => Some(101),
-        "No" | "Nobelium" => Some(102),
-        "Lr" | "Lawrencium" => Some(103),
-        "Rf" | "Rutherfordium" => Some(104),
-        "Db" | "Dubnium" => Some(105),
-        "Sg" | "Seaborgium" => Some(106),
-        "Bh" | "Bohrium" => Some(107),
-        "Hs" | "Hassium" => Some(108),
-        "Mt" | "Meitnerium" => Some(109),
-        "Ds" | "Darmstadtium" => Some(110),
-        "Rg" | "Roentgenium" => Some(111),
-        "Cn" | "Copernicium" => Some(112),
-        "Nh" | "Nihonium" => Some(113),
-        "Fl" | "Flerovium" => Some(114),
-        "Mc" | "Moscovium" => Some(115),
-        "Lv" | "Livermorium" => Some(116),
-        "Ts" | "Tennessine" => Some(117),
-        "Og" | "Oganesson" => Some(118),
+    Element::from_symbol(element).or_else(|| Element::from_name(element)).map(|element| element.atomic_number)
+}
+
+// Gets the experimentally observed ground state occupancies that differ from the Madelung rule
+// Each entry is (n, subshell, electrons), e.g. 24 -> [(3, 'd', 5), (4, 's', 1)] for Cr
+pub fn configuration_exception(atomic_number: u32) -> Option<&'static [(u32, char, u32)]> {
+    match atomic_number {
+        24 => Some(&[(3, 'd', 5), (4, 's', 1)]), // Cr
+        29 => Some(&[(3, 'd', 10), (4, 's', 1)]), // Cu
+        41 => Some(&[(4, 'd', 4), (5, 's', 1)]), // Nb
+        42 => Some(&[(4, 'd', 5), (5, 's', 1)]), // Mo
+        44 => Some(&[(4, 'd', 7), (5, 's', 1)]), // Ru
+        45 => Some(&[(4, 'd', 8), (5, 's', 1)]), // Rh
+        46 => Some(&[(4, 'd', 10), (5, 's', 0)]), // Pd
+        47 => Some(&[(4, 'd', 10), (5, 's', 1)]), // Ag
+        57 => Some(&[(4, 'f', 0), (5, 'd', 1)]), // La
+        58 => Some(&[(4, 'f', 1), (5, 'd', 1)]), // Ce
+        64 => Some(&[(4, 'f', 7), (5, 'd', 1)]), // Gd
+        78 => Some(&[(5, 'd', 9), (6, 's', 1)]), // Pt
+        79 => Some(&[(5, 'd', 10), (6, 's', 1)]), // Au
+        89 => Some(&[(5, 'f', 0), (6, 'd', 1)]), // Ac
+        90 => Some(&[(5, 'f', 0), (6, 'd', 2)]), // Th
+        91 => Some(&[(5, 'f', 2), (6, 'd', 1)]), // Pa
+        92 => Some(&[(5, 'f', 3), (6, 'd', 1)]), // U
+        93 => Some(&[(5, 'f', 4), (6, 'd', 1)]), // Np
+        96 => Some(&[(5, 'f', 7), (6, 'd', 1)]), // Cm
+        103 => Some(&[(6, 'd', 0), (7, 'p', 1)]), // Lr
         _ => None,
     }
 }
 
-// Gets the element name of a given atomic number (e.g. 1 -> "H")
+// Gets the element symbol of a given atomic number (e.g. 1 -> "H")
 pub fn element_name(atomic_number: u32) -> Option<String> {
-    match atomic_number {
-        1 => Some(String::from("H")),
-        2 => Some(String::from("He")),
-        3 => Some(String::from("Li")),
-        4 => Some(String::from("Be")),
-        5 => Some(String::from("B")),
-        6 => Some(String::from("C")),
-        7 => Some(String::from("N")),
-        8 => Some(String::from("O")),
-        9 => Some(String::from("F")),
-        10 => Some(String::from("Ne")),
-        11 => Some(String::from("Na")),
-        12 => Some(String::from("Mg")),
-        13 => Some(String::from("Al")),
-        14 => Some(String::from("Si")),
-        15 => Some(String::from("P")),
-        16 => Some(String::from("S")),
-        17 => Some(String::from("Cl")),
-        18 => Some(String::from("Ar")),
-        19 => Some(String::from("K")),
-        20 => Some(String::from("Ca")),
-        21 => Some(String::from("Sc")),
-        22 => Some(String::from("Ti")),
-        23 => Some(String::from("V")),
-        24 => Some(String::from("Cr")),
-        25 => Some(String::from("Mn")),
-        26 => Some(String::from("Fe")),
-        27 => Some(String::from("Co")),
-        28 => Some(String::from("Ni")),
-        29 => Some(String::from("Cu")),
-        30 => Some(String::from("Zn")),
-        31 => Some(String::from("Ga")),
-        32 => Some(String::from("Ge")),
-        33 => Some(String::from("As")),
-        34 => Some(String::from("Se")),
-        35 => Some(String::from("Br")),
-        36 => Some(String::from("Kr")),
-        37 => Some(String::from("Rb")),
-        38 => Some(String::from("Sr")),
-        39 => Some(String::from("Y")),
-        40 => Some(String::from("Zr")),
-        41 => Some(String::from("Nb")),
-        42 => Some(String::from("Mo")),
-        43 => Some(String::from("Tc")),
-        44 => Some(String::from("Ru")),
-        45 => Some(String::from("Rh")),
-        46 => Some(String::from("Pd")),
-        47 => Some(String::from("Ag")),
-        48 => Some(String::from("Cd")),
-        49 => Some(String::from("In")),
-        50 => Some(String::from("Sn")),
-        51 => Some(String::from("Sb")),
-        52 => Some(String::from("Te")),
-        53 => Some(String::from("I")),
-        54 => Some(String::from("Xe")),
-        55 => Some(String::from("Cs")),
-        56 => Some(String::from("Ba")),
-        57 => Some(String::from("La")),
-        58 => Some(String::from("Ce")),
-        59 => Some(String::from("Pr")),
-        60 => Some(String::from("Nd")),
-        61 => Some(String::from("Pm")),
-        62 => Some(String::from("Sm")),
-        63 => Some(String::from("Eu")),
-        64 => Some(String::from("Gd")),
-        65 => Some(String::from("Tb")),
-        66 => Some(String::from("Dy")),
-        67 => Some(String::from("Ho")),
-        68 => Some(String::from("Er")),
-        69 => Some(String::from("Tm")),
-        70 => Some(String::from("Yb")),
-        71 => Some(String::from("Lu")),
-        72 => Some(String::from("Hf")),
-        73 => Some(String::from("Ta")),
-        74 => Some(String::from("W")),
-        75 => Some(String::from("Re")),
-        76 => Some(String::from("Os")),
-        77 => Some(String::from("Ir")),
-        78 => Some(String::from("Pt")),
-        79 => Some(String::from("Au")),
-        80 => Some(String::from("Hg")),
-        81 => Some(String::from("Tl")),
-        82 => Some(String::from("Pb")),
-        83 => Some(String::from("Bi")),
-        84 => Some(String::from("Po")),
-        85 => Some(String::from("At")),
-        86 => Some(String::from("Rn")),
-        87 => Some(String::from("Fr")),
-        88 => Some(String::from("Ra")),
-        89 => Some(String::from("Ac")),
-        90 => Some(String::from("Th")),
-        91 => Some(String::from("Pa")),
-        92 => Some(String::from("U")),
-        93 => Some(String::from("Np")),
-        94 => Some(String::from("Pu")),
-        95 => Some(String::from("Am")),
-        96 => Some(String::from("Cm")),
-        97 => Some(String::from("Bk")),
-        98 => Some(String::from("Cf")),
-        99 => Some(String::from("Es")),
-        100 => Some(String::from("Fm")),
-        101 => Some(String::from("Md")),
-        102 => Some(String::from("No")),
-        103 => Some(String::from("Lr")),
-        104 => Some(String::from("Rf")),
-        105 => Some(String::from("Db")),
-        106 => Some(String::from("Sg")),
-        107 => Some(String::from("Bh")),
-        108 => Some(String::from("Hs")),
-        109 => Some(String::from("Mt")),
-        110 => Some(String::from("Ds")),
-        111 => Some(String::from("Rg")),
-        112 => Some(String::from("Cn")),
-        113 => Some(String::from("Nh")),
-        114 => Some(String::from("Fl")),
-        115 => Some(String::from("Mc")),
-        116 => Some(String::from("Lv")),
-        117 => Some(String::from("Ts")),
-        118 => Some(String::from("Og")),
-        _ => None,
+    Element::from_atomic_number(atomic_number).map(|element| String::from(element.symbol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_table_is_ordered() {
+        for (index, element) in ELEMENTS.iter().enumerate() {
+            assert_eq!(element.atomic_number as usize, index + 1, "{}", element.symbol);
+        }
+    }
+
+    #[test]
+    fn test_element_lookup() {
+        assert_eq!(Element::from_atomic_number(26).unwrap().symbol, "Fe");
+        assert_eq!(Element::from_symbol("Fe").unwrap().name, "Iron");
+        assert_eq!(Element::from_name("iron").unwrap().atomic_number, 26);
+        assert_eq!(Element::from_name("Aluminium").unwrap().symbol, "Al");
+        assert_eq!(Element::lookup("79").unwrap().symbol, "Au");
+        assert_eq!(Element::lookup("Og").unwrap().period, 7);
+        assert_eq!(Element::from_symbol("FE"), None);
+        assert_eq!(Element::from_atomic_number(0), None);
+        assert_eq!(Element::from_atomic_number(119), None);
+    }
+
+    #[test]
+    fn test_element_placement() {
+        let cases = [("H", Some(1), 1, Block::S), ("He", Some(18), 1, Block::S), ("B", Some(13), 2, Block::P), ("Sc", Some(3), 4, Block::D), ("Ce", None, 6, Block::F), ("Lu", Some(3), 6, Block::D), ("Hg", Some(12), 6, Block::D), ("Og", Some(18), 7, Block::P)];
+
+        for (symbol, group, period, block) in cases {
+            let element = Element::from_symbol(symbol).unwrap();
+
+            assert_eq!((element.group, element.period, element.block), (group, period, block), "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn test_element_properties() {
+        let iron = Element::from_symbol("Fe").unwrap();
+        assert_eq!(Property::Electronegativity.value(iron), Some(1.83));
+        assert_eq!(iron.properties().ionic_radius, Some((3, 64.5)));
+        assert_eq!(iron.ionization_energy(2), Some(1561.9));
+        assert_eq!(iron.ionization_energy(0), None);
+        assert_eq!(iron.properties().oxidation_states, &[2, 3]);
+
+        // Neon's successive ionization energies jump once only the 1s electrons remain
+        let neon = Element::from_symbol("Ne").unwrap();
+        assert_eq!(neon.properties().ionization_energies.len(), 10);
+        assert!(neon.ionization_energy(9).unwrap() > 4.0 * neon.ionization_energy(8).unwrap());
+
+        assert_eq!(Property::Electronegativity.value(neon), None);
+        assert_eq!(Property::Electronegativity.value(Element::from_symbol("Og").unwrap()), None);
+    }
+
+    #[test]
+    fn test_element_display() {
+        let iron = Element::from_symbol("Fe").unwrap().to_string();
+        assert!(iron.contains("Ionization energies: 762.5, 1561.9, 2957 kJ/mol (first 3 of 26 listed)"), "{}", iron);
+        assert!(iron.contains(&format!("\n    Electronegativity: {}", Property::Electronegativity.source())));
+        assert!(iron.contains(&format!("\n    Oxidation states: {}", OXIDATION_STATE_SOURCE)));
+        assert!(iron.contains(&format!("\n    Isotopes: {}", ISOTOPE_SOURCE)));
+
+        let neon = Element::from_symbol("Ne").unwrap().to_string();
+        assert!(neon.contains("131432 kJ/mol\n"), "{}", neon);
+        assert!(!neon.contains("Oxidation states:"));
+    }
+
+    #[test]
+    fn test_property_ranking() {
+        assert_eq!(Property::Electronegativity.ranked()[0].0.symbol, "F");
+        assert_eq!(Property::IonizationEnergy.ranked()[0].0.symbol, "He");
+        assert_eq!(Property::ElectronAffinity.ranked()[0].0.symbol, "Cl");
+        assert_eq!(Property::Density.ranked()[0].0.symbol, "Os");
+        assert_eq!(Property::CovalentRadius.ranked().last().unwrap().0.symbol, "He");
+    }
+
+    #[test]
+    fn test_isotope_abundances() {
+        // Natural abundances of every listed element should add up to 1
+        for element in ELEMENTS.iter() {
+            let total: f64 = element.isotopes().map(|isotope| isotope.abundance).sum();
+
+            if total > 0.0 {
+                assert!((total - 1.0).abs() < 1e-4, "{}: {}", element.symbol, total);
+            }
+        }
+
+        // Every element up to Bi has natural isotopes, except Tc and Pm
+        for element in ELEMENTS.iter().take(83).filter(|element| !["Tc", "Pm"].contains(&element.symbol)) {
+            assert!(element.isotopes().any(|isotope| isotope.abundance > 0.0), "{}", element.symbol);
+        }
+
+        let carbon: Vec<&Isotope> = Element::from_symbol("C").unwrap().isotopes().collect();
+        assert_eq!(carbon.iter().map(|isotope| isotope.mass_number).collect::<Vec<u32>>(), vec![12, 13, 14]);
+        assert!(carbon[0].is_stable());
+        assert!(!carbon[2].is_stable());
     }
 }
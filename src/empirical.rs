@@ -0,0 +1,220 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::constants::*;
+use crate::formula::*;
+use crate::readers::*;
+
+// The largest whole number the mole ratios are multiplied by when looking for a whole number ratio
+const MAX_MULTIPLIER: u32 = 6;
+// How far from a whole number a multiplied mole ratio can be before it's rejected
+const RATIO_TOLERANCE: f64 = 0.1;
+
+// The simplest whole number ratio of elements, found from the mass of each element in a sample
+pub struct EmpiricalFormula {
+    // Mass (g), moles and mole ratio (relative to the smallest) of each element
+    pub amounts: Vec<(&'static Element, f64, f64, f64)>,
+    // What the mole ratios had to be multiplied by to become whole numbers
+    pub multiplier: u32,
+    pub formula: Formula,
+    // The molecular formula and how many empirical units it contains, if a molar mass was given
+    pub molecular: Option<(Formula, u32)>,
+}
+
+impl EmpiricalFormula {
+    // Finds the empirical formula from the mass of each element (in g)
+    // Mass percentages work as well, by treating them as the masses in a 100 g sample
+    // First converts each mass to moles, then divides by the smallest number of moles
+    // If the ratios aren't whole numbers, tries multiplying them by 2, 3, 4... until they are
+    // Returns None if an element is listed twice or no small multiplier gives a whole number ratio
+    pub fn new(masses: &[(&'static Element, f64)]) -> Option<Self> {
+        if masses.iter().enumerate().any(|(index, (element, _))| {
+            masses[..index].iter().any(|(listed, _)| listed.atomic_number == element.atomic_number)
+        }) {
+            return None;
+        }
+
+        let moles: Vec<f64> = masses.iter().map(|(element, mass)| mass / element.atomic_weight).collect();
+
+        if moles.iter().any(|moles| *moles <= 0.0) {
+            return None;
+        }
+
+        let smallest = moles.iter().copied().reduce(f64::min)?;
+        let ratios: Vec<f64> = moles.iter().map(|moles| moles / smallest).collect();
+
+        let multiplier = (1..=MAX_MULTIPLIER).find(|multiplier| {
+            ratios.iter().all(|ratio| {
+                let scaled = ratio * *multiplier as f64;
+                (scaled - scaled.round()).abs() <= RATIO_TOLERANCE
+            })
+        })?;
+
+        let counts = masses.iter().zip(&ratios)
+            .map(|((element, _), ratio)| (*element, (ratio * multiplier as f64).round() as u32))
+            .collect();
+
+        Some(Self {
+            amounts: masses.iter().zip(moles.iter().zip(&ratios))
+                .map(|((element, mass), (moles, ratio))| (*element, *mass, *moles, *ratio))
+                .collect(),
+            multiplier,
+            formula: Formula::from_counts(counts),
+            molecular: None,
+        })
+    }
+
+    // Finds the empirical formula of a compound containing only C, H and possibly O from combustion analysis
+    // All of the carbon ends up in CO2 and all of the hydrogen ends up in H2O
+    // Any mass of the sample not accounted for by C and H is taken to be O
+    pub fn from_combustion(sample_mass: f64, carbon_dioxide_mass: f64, water_mass: f64) -> Option<Self> {
+        let carbon = Element::from_symbol("C")?;
+        let hydrogen = Element::from_symbol("H")?;
+        let oxygen = Element::from_symbol("O")?;
+
+        let carbon_mass = carbon_dioxide_mass * carbon.atomic_weight / Formula::parse("CO2")?.molar_mass();
+        let hydrogen_mass = water_mass * 2.0 * hydrogen.atomic_weight / Formula::parse("H2O")?.molar_mass();
+        let oxygen_mass = sample_mass - carbon_mass - hydrogen_mass;
+
+        let mut masses = vec![(carbon, carbon_mass), (hydrogen, hydrogen_mass)];
+
+        // Allow for a little rounding in the measured masses before deciding there is oxygen
+        if oxygen_mass > sample_mass * 0.01 {
+            masses.push((oxygen, oxygen_mass));
+        } else if oxygen_mass < -sample_mass * 0.01 {
+            return None;
+        }
+
+        Self::new(&masses)
+    }
+
+    // Scales the empirical formula up to the molecular formula with the given molar mass (in g/mol)
+    // Returns None if the molar mass isn't close to a whole number multiple of the empirical formula mass,
+    // or the molecular formula has too many atoms to count
+    pub fn with_molar_mass(mut self, molar_mass: f64) -> Option<Self> {
+        let units = molar_mass / self.formula.molar_mass();
+        let whole_units = units.round();
+
+        if whole_units < 1.0 || whole_units > u32::MAX as f64 || (units - whole_units).abs() > RATIO_TOLERANCE {
+            return None;
+        }
+
+        let whole_units = whole_units as u32;
+        let counts = self.formula.counts.iter()
+            .map(|(element, count)| Some((*element, count.checked_mul(whole_units)?)))
+            .collect::<Option<_>>()?;
+
+        self.molecular = Some((Formula::from_counts(counts), whole_units));
+
+        Some(self)
+    }
+
+    // Asks for percentages, grams or combustion data, and optionally a molar mass
+    // Returns None if the input is invalid or gives no whole number ratio
+    pub fn prompt() -> Option<Self> {
+        let empirical_formula = match read_i32("Enter 1 for mass percentages, 2 for grams of each element or 3 for combustion analysis (C, H, O): ") {
+            3 => Self::from_combustion(
+                read_f64("Enter the mass of the sample (in g): "),
+                read_f64("Enter the mass of CO2 produced (in g): "),
+                read_f64("Enter the mass of H2O produced (in g): "),
+            )?,
+            choice => {
+                let unit = if choice == 1 { "%" } else { "g" };
+                let element_count = read_i32("Enter the number of elements: ");
+                let mut masses = Vec::new();
+
+                for _ in 0..element_count {
+                    let element = Element::from_symbol(&read_string("Enter the element symbol: "))?;
+                    masses.push((element, read_f64(&format!("Enter the amount of {} (in {}): ", element.symbol, unit))));
+                }
+
+                Self::new(&masses)?
+            }
+        };
+
+        match read_f64("Enter the molar mass (in g/mol), or 0 to skip: ") {
+            molar_mass if molar_mass > 0.0 => empirical_formula.with_molar_mass(molar_mass),
+            _ => Some(empirical_formula),
+        }
+    }
+}
+
+impl Display for EmpiricalFormula {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (element, mass, moles, ratio) in &self.amounts {
+            writeln!(f, "{}: {:.4} g / {} g/mol = {:.4} mol, ratio {:.3}", element.symbol, mass, element.atomic_weight, moles, ratio)?;
+        }
+
+        if self.multiplier > 1 {
+            writeln!(f, "Ratios multiplied by {} to get whole numbers", self.multiplier)?;
+        }
+
+        write!(f, "Empirical formula: {} ({:.3} g/mol)", self.formula, self.formula.molar_mass())?;
+
+        if let Some((molecular, units)) = &self.molecular {
+            write!(f, "\nMolecular formula: {} ({:.3} g/mol, {} empirical units)", molecular, molecular.molar_mass(), units)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(symbol: &str) -> &'static Element {
+        Element::from_symbol(symbol).unwrap()
+    }
+
+    #[test]
+    fn test_empirical_formula() {
+        let glucose = EmpiricalFormula::new(&[(element("C"), 40.0), (element("H"), 6.71), (element("O"), 53.3)]).unwrap();
+        assert_eq!(glucose.formula.text, "CH2O");
+        assert_eq!(glucose.multiplier, 1);
+
+        let iron_oxide = EmpiricalFormula::new(&[(element("Fe"), 69.94), (element("O"), 30.06)]).unwrap();
+        assert_eq!(iron_oxide.formula.text, "Fe2O3");
+        assert_eq!(iron_oxide.multiplier, 2);
+
+        let phosphorus_oxide = EmpiricalFormula::new(&[(element("P"), 43.64), (element("O"), 56.36)]).unwrap();
+        assert_eq!(phosphorus_oxide.formula.text, "P2O5");
+
+        let propane = EmpiricalFormula::new(&[(element("C"), 81.71), (element("H"), 18.29)]).unwrap();
+        assert_eq!(propane.formula.text, "C3H8");
+        assert_eq!(propane.multiplier, 3);
+
+        assert!(EmpiricalFormula::new(&[(element("Na"), 50.0), (element("Na"), 50.0)]).is_none());
+    }
+
+    #[test]
+    fn test_molecular_formula() {
+        let glucose = EmpiricalFormula::new(&[(element("C"), 40.0), (element("H"), 6.71), (element("O"), 53.3)]).unwrap();
+        let glucose = glucose.with_molar_mass(180.16).unwrap();
+        let (molecular, units) = glucose.molecular.unwrap();
+
+        assert_eq!(molecular.text, "C6H12O6");
+        assert_eq!(units, 6);
+
+        let water = EmpiricalFormula::new(&[(element("H"), 11.19), (element("O"), 88.81)]).unwrap();
+        assert!(water.with_molar_mass(27.0).is_none());
+
+        let water = EmpiricalFormula::new(&[(element("H"), 11.19), (element("O"), 88.81)]).unwrap();
+        assert!(water.with_molar_mass(1e30).is_none());
+
+        // 3 billion empirical units fit in a u32, but 6 billion hydrogen atoms don't
+        let water = EmpiricalFormula::new(&[(element("H"), 11.19), (element("O"), 88.81)]).unwrap();
+        let molar_mass = water.formula.molar_mass() * 3e9;
+        assert!(water.with_molar_mass(molar_mass).is_none());
+    }
+
+    #[test]
+    fn test_combustion_analysis() {
+        // 1.000 g of ethanol (C2H6O) burns to 1.911 g CO2 and 1.173 g H2O
+        let ethanol = EmpiricalFormula::from_combustion(1.000, 1.911, 1.173).unwrap();
+        assert_eq!(ethanol.formula.text, "C2H6O");
+
+        // 1.000 g of hexane (C6H14) burns to 3.064 g CO2 and 1.464 g H2O
+        let hexane = EmpiricalFormula::from_combustion(1.000, 3.064, 1.464).unwrap();
+        assert_eq!(hexane.formula.text, "C3H7");
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Mul, Sub};
+
+use crate::formula::*;
+
+// An unbalanced reaction, e.g. "C3H8 + O2 -> CO2 + H2O"
+pub struct Reaction {
+    pub reactants: Vec<Formula>,
+    pub products: Vec<Formula>,
+}
+
+impl Reaction {
+    // Parses the two sides of a reaction, separated by ->, →, =, <=> or ⇌
+    // Species are separated by a +, with or without spaces (e.g. "C3H8+O2->CO2+H2O")
+    // Any coefficients already in the equation are ignored, and electrons can be written as e-
+    pub fn parse(input: &str) -> Option<Self> {
+        let (reactants, products) = ["<=>", "⇌", "->", "→", "="].iter()
+            .find_map(|arrow| input.split_once(arrow))?;
+
+        Some(Self {
+            reactants: Self::parse_side(reactants)?,
+            products: Self::parse_side(products)?,
+        })
+    }
+
+    fn parse_side(side: &str) -> Option<Vec<Formula>> {
+        Self::split_species(side).into_iter()
+            .map(|species| {
+                let species = species.trim().trim_start_matches(|c: char| c.is_ascii_digit() || c.is_whitespace());
+
+                match species {
+                    "e-" | "e⁻" => Some(Formula { text: species.to_string(), counts: Vec::new(), charge: -1 }),
+                    _ => Formula::parse(species),
+                }
+            })
+            .collect()
+    }
+
+    // Splits a side at each + that is followed by another species
+    // A + followed by another + or by nothing is a charge, so "Fe3++e-" and "Fe3+ + e-" are both Fe3+ and e-
+    fn split_species(side: &str) -> Vec<&str> {
+        let mut species = Vec::new();
+        let mut start = 0;
+
+        for (index, c) in side.char_indices() {
+            if c == '+' && side[index + 1..].trim_start().starts_with(|next: char| next != '+') {
+                species.push(&side[start..index]);
+                start = index + 1;
+            }
+        }
+
+        species.push(&side[start..]);
+        species
+    }
+
+    // Balances the reaction by finding the nullspace of its composition matrix
+    // Each row of the matrix is an element (or the charge), and each column is a species
+    // Reactant columns are positive and product columns negative, so a vector of coefficients in the
+    // nullspace conserves every element (and the charge)
+        // If the nullspace is empty, no set of coefficients balances the reaction
+        // If it has more than one dimension, there are several independent ways to balance it
+        // Otherwise, it is scaled to the smallest whole numbers, which must all be positive
+    pub fn balance(&self) -> Balance {
+        let species: Vec<(&Formula, i128)> = self.reactants.iter().map(|formula| (formula, 1))
+            .chain(self.products.iter().map(|formula| (formula, -1)))
+            .collect();
+
+        let mut elements: Vec<u32> = Vec::new();
+
+        for (formula, _) in &species {
+            for (element, _) in &formula.counts {
+                if !elements.contains(&element.atomic_number) {
+                    elements.push(element.atomic_number);
+                }
+            }
+        }
+
+        let mut matrix: Vec<Vec<Rational>> = elements.iter()
+            .map(|atomic_number| species.iter()
+                .map(|(formula, sign)| {
+                    let count = formula.counts.iter()
+                        .find(|(element, _)| element.atomic_number == *atomic_number)
+                        .map_or(0, |(_, count)| *count);
+
+                    Rational::from(sign * count as i128)
+                })
+                .collect())
+            .collect();
+
+        if species.iter().any(|(formula, _)| formula.charge != 0) {
+            matrix.push(species.iter().map(|(formula, sign)| Rational::from(sign * formula.charge as i128)).collect());
+        }
+
+        let nullspace = nullspace(matrix, species.len());
+
+        match nullspace.len() {
+            0 => Balance::Impossible,
+            1 => {
+                let coefficients = whole_numbers(&nullspace[0]);
+
+                // A species with a zero coefficient doesn't take part, and mixed signs mean a species is on the wrong side
+                if coefficients.iter().all(|coefficient| *coefficient > 0) || coefficients.iter().all(|coefficient| *coefficient < 0) {
+                    let mut coefficients = coefficients.iter().map(|coefficient| coefficient.unsigned_abs() as u32);
+
+                    Balance::Balanced(BalancedEquation {
+                        reactants: self.reactants.iter().map(|formula| (coefficients.next().unwrap(), formula.clone())).collect(),
+                        products: self.products.iter().map(|formula| (coefficients.next().unwrap(), formula.clone())).collect(),
+                    })
+                } else {
+                    Balance::Impossible
+                }
+            }
+            dimensions => Balance::Underdetermined(dimensions),
+        }
+    }
+}
+
+// The outcome of trying to balance a reaction
+pub enum Balance {
+    Balanced(BalancedEquation),
+    // No positive coefficients conserve every element and the charge
+    Impossible,
+    // There are this many independent ways to balance the reaction (e.g. two reactions written as one)
+    Underdetermined(usize),
+}
+
+impl Display for Balance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Balance::Balanced(equation) => write!(f, "{}", equation),
+            Balance::Impossible => write!(f, "The equation cannot be balanced."),
+            Balance::Underdetermined(dimensions) => write!(f, "The equation is underdetermined: there are {} independent ways to balance it.", dimensions),
+        }
+    }
+}
+
+// A reaction with the whole number coefficient of each species
+pub struct BalancedEquation {
+    pub reactants: Vec<(u32, Formula)>,
+    pub products: Vec<(u32, Formula)>,
+}
+
+impl BalancedEquation {
+    // Gets every species with its coefficient, reactants first
+    pub fn species(&self) -> impl Iterator<Item = &(u32, Formula)> {
+        self.reactants.iter().chain(&self.products)
+    }
+
+    // Finds a species by its formula, as written in the equation
+    pub fn find(&self, formula: &str) -> Option<&(u32, Formula)> {
+        self.species().find(|(_, species)| species.text == formula.trim())
+    }
+}
+
+impl Display for BalancedEquation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let side = |species: &[(u32, Formula)]| species.iter()
+            .map(|(coefficient, formula)| match coefficient {
+                1 => formula.to_string(),
+                coefficient => format!("{}{}", coefficient, formula),
+            })
+            .collect::<Vec<_>>()
+            .join(" + ");
+
+        write!(f, "{} -> {}", side(&self.reactants), side(&self.products))
+    }
+}
+
+// An exact fraction, always stored in lowest terms with a positive denominator
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    fn new(numerator: i128, denominator: i128) -> Self {
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    fn reciprocal(&self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self::new(value, 1)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.numerator * other.numerator, self.denominator * other.denominator)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.numerator * other.denominator - other.numerator * self.denominator, self.denominator * other.denominator)
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.max(1)
+}
+
+// Gets a basis for the nullspace of a matrix with the given number of columns
+// Reduces the matrix to reduced row echelon form, then builds one basis vector per free column
+fn nullspace(mut matrix: Vec<Vec<Rational>>, columns: usize) -> Vec<Vec<Rational>> {
+    let mut pivot_columns = Vec::new();
+    let mut row = 0;
+
+    for column in 0..columns {
+        let Some(pivot_row) = (row..matrix.len()).find(|&candidate| !matrix[candidate][column].is_zero()) else {
+            continue;
+        };
+
+        matrix.swap(row, pivot_row);
+
+        let scale = matrix[row][column].reciprocal();
+        matrix[row] = matrix[row].iter().map(|value| *value * scale).collect();
+
+        for other_row in 0..matrix.len() {
+            if other_row != row && !matrix[other_row][column].is_zero() {
+                let factor = matrix[other_row][column];
+                matrix[other_row] = matrix[other_row].iter().zip(&matrix[row])
+                    .map(|(value, pivot_value)| *value - factor * *pivot_value)
+                    .collect();
+            }
+        }
+
+        pivot_columns.push(column);
+        row += 1;
+    }
+
+    (0..columns)
+        .filter(|column| !pivot_columns.contains(column))
+        .map(|free_column| {
+            let mut vector = vec![Rational::from(0); columns];
+            vector[free_column] = Rational::from(1);
+
+            for (pivot_row, pivot_column) in pivot_columns.iter().enumerate() {
+                vector[*pivot_column] = Rational::from(0) - matrix[pivot_row][free_column];
+            }
+
+            vector
+        })
+        .collect()
+}
+
+// Scales a vector of fractions to the smallest whole numbers with the same ratios
+fn whole_numbers(vector: &[Rational]) -> Vec<i128> {
+    let lcm = vector.iter().fold(1, |lcm, value| lcm / gcd(lcm, value.denominator) * value.denominator);
+    let integers: Vec<i128> = vector.iter().map(|value| value.numerator * (lcm / value.denominator)).collect();
+    let divisor = integers.iter().fold(0, |divisor, value| gcd(divisor, *value));
+
+    integers.iter().map(|value| value / divisor).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(input: &str) -> String {
+        Reaction::parse(input).unwrap().balance().to_string()
+    }
+
+    #[test]
+    fn test_balance() {
+        assert_eq!(balance("C3H8 + O2 -> CO2 + H2O"), "C3H8 + 5O2 -> 3CO2 + 4H2O");
+        assert_eq!(balance("H2 + O2 = H2O"), "2H2 + O2 -> 2H2O");
+        assert_eq!(balance("Fe + O2 → Fe2O3"), "4Fe + 3O2 -> 2Fe2O3");
+        assert_eq!(balance("2KMnO4 + HCl -> KCl + MnCl2 + H2O + Cl2"), "2KMnO4 + 16HCl -> 2KCl + 2MnCl2 + 8H2O + 5Cl2");
+        assert_eq!(balance("Ca3(PO4)2 + H2SO4 -> CaSO4 + H3PO4"), "Ca3(PO4)2 + 3H2SO4 -> 3CaSO4 + 2H3PO4");
+        assert_eq!(balance("CuSO4·5H2O -> CuSO4 + H2O"), "CuSO4·5H2O -> CuSO4 + 5H2O");
+    }
+
+    #[test]
+    fn test_balance_ions() {
+        assert_eq!(balance("Fe3+ + e- -> Fe2+"), "Fe3+ + e- -> Fe2+");
+        assert_eq!(balance("MnO4- + Fe2+ + H+ -> Mn2+ + Fe3+ + H2O"), "MnO4- + 5Fe2+ + 8H+ -> Mn2+ + 5Fe3+ + 4H2O");
+        assert_eq!(balance("Cu + Ag+ -> Cu2+ + Ag"), "Cu + 2Ag+ -> Cu2+ + 2Ag");
+        assert_eq!(balance("Cu+Ag+->Cu2++Ag"), "Cu + 2Ag+ -> Cu2+ + 2Ag");
+        assert_eq!(balance("Fe3++e-->Fe2+"), "Fe3+ + e- -> Fe2+");
+    }
+
+    #[test]
+    fn test_unbalanceable() {
+        assert_eq!(balance("H2O -> CO2"), "The equation cannot be balanced.");
+        assert_eq!(balance("H2O + NaCl -> H2O"), "The equation cannot be balanced.");
+        assert_eq!(balance("H2 + O2 -> H2O + H2O2"), "The equation is underdetermined: there are 2 independent ways to balance it.");
+    }
+
+    #[test]
+    fn test_parse_reaction() {
+        assert!(Reaction::parse("H2 + O2").is_none());
+        assert!(Reaction::parse("H2 + Xx -> H2").is_none());
+
+        let reaction = Reaction::parse("C3H8+O2->CO2+H2O").unwrap();
+        assert_eq!(reaction.reactants.len(), 2);
+        assert_eq!(reaction.products.len(), 2);
+    }
+}
@@ -0,0 +1,372 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::constants::*;
+use crate::measurements::exponent;
+use crate::quantity::*;
+use crate::readers::*;
+
+// A parsed chemical formula, e.g. "Ca3(PO4)2", "K4[Fe(CN)6]", "CuSO4·5H2O" or "SO4^2-"
+// Element counts are kept in order of first appearance
+#[derive(Clone)]
+pub struct Formula {
+    pub text: String,
+    pub counts: Vec<(&'static Element, u32)>,
+    pub charge: i32,
+}
+
+impl Formula {
+    // Parses a formula made of element symbols, counts and nested () or [] groups
+    // Hydrates are joined with ·, •, * or . and may have a leading count (e.g. CuSO4·5H2O)
+    // A charge goes at the end as an optional ^, optional magnitude and a sign (e.g. Fe3+, NH4+, SO4^2-)
+    // Without the ^, digits before the sign are only the charge for a single element (Fe3+), and are a count otherwise (NH4+)
+    pub fn parse(input: &str) -> Option<Self> {
+        let text = input.trim();
+        let (body, charge) = Self::split_charge(text)?;
+        let mut counts = Vec::new();
+
+        for (index, part) in body.split(['·', '•', '*', '.']).enumerate() {
+            let part = part.trim();
+
+            // Only the hydrated parts can have a leading count, e.g. the 5 in ·5H2O
+            let digits = part.find(|c: char| !c.is_ascii_digit()).unwrap_or(part.len());
+            let multiplier = match &part[..digits] {
+                "" => 1,
+                _ if index == 0 => return None,
+                digits => digits.parse().ok()?,
+            };
+
+            let mut parser = Parser { chars: part[digits..].chars().collect(), position: 0 };
+            let part_counts = parser.group(None)?;
+
+            if part_counts.is_empty() {
+                return None;
+            }
+
+            for (element, count) in part_counts {
+                add_count(&mut counts, element, count.checked_mul(multiplier)?)?;
+            }
+        }
+
+        Some(Self {
+            text: text.to_string(),
+            counts,
+            charge,
+        })
+    }
+
+    // Creates a neutral formula from element counts, writing counts of 1 as just the symbol (e.g. CH2O)
+    pub fn from_counts(counts: Vec<(&'static Element, u32)>) -> Self {
+        let text = counts.iter()
+            .map(|(element, count)| match count {
+                1 => element.symbol.to_string(),
+                count => format!("{}{}", element.symbol, count),
+            })
+            .collect();
+
+        Self {
+            text,
+            counts,
+            charge: 0,
+        }
+    }
+
+    // Splits the charge off the end of a formula, returning the rest of the formula and the charge
+    fn split_charge(text: &str) -> Option<(&str, i32)> {
+        let sign = match text.chars().last()? {
+            '+' => 1,
+            '-' => -1,
+            _ => return Some((text, 0)),
+        };
+
+        let rest = &text[..text.len() - 1];
+        let digits_start = rest.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        let (body, digits) = rest.split_at(digits_start);
+
+        let body = match body.strip_suffix('^') {
+            Some(body) => body,
+            None if Element::from_symbol(body.trim_end()).is_some() => body,
+            None => return Some((rest.trim_end(), sign)),
+        };
+
+        let magnitude = match digits {
+            "" => 1,
+            digits => digits.parse().ok()?,
+        };
+
+        Some((body.trim_end(), sign * magnitude))
+    }
+
+    // Gets the number of atoms of the given element in the formula
+    pub fn count(&self, element: &Element) -> u32 {
+        self.counts.iter()
+            .find(|(counted, _)| counted.atomic_number == element.atomic_number)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    // Gets the molar mass (in g/mol) by adding up the atomic weight of every atom
+    pub fn molar_mass(&self) -> f64 {
+        self.counts.iter().map(|(element, count)| element.atomic_weight * *count as f64).sum()
+    }
+
+    // Gets the molar mass with the significant figures the atomic weights are known to
+    // The counts are exact, so the total is limited by the least precise decimal place among the weights
+    pub fn measured_molar_mass(&self) -> Option<Quantity<GramPerMole>> {
+        self.counts.iter().map(|(element, count)| atomic_weight(element) * *count as f64).reduce(|total, mass| total + mass)
+    }
+}
+
+// Gets an atomic weight (in g/mol) with the significant figures it is tabulated to
+fn atomic_weight(element: &Element) -> Quantity<GramPerMole> {
+    Quantity::measured(element.atomic_weight, count_significant_figures(&element.atomic_weight.to_string()))
+}
+
+impl Display for Formula {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+// Adds atoms of an element to a list of counts, keeping the order of first appearance
+// Returns None if the total count overflows
+fn add_count(counts: &mut Vec<(&'static Element, u32)>, element: &'static Element, count: u32) -> Option<()> {
+    match counts.iter_mut().find(|(counted, _)| counted.atomic_number == element.atomic_number) {
+        Some((_, total)) => *total = total.checked_add(count)?,
+        None => counts.push((element, count)),
+    }
+
+    Some(())
+}
+
+// Recursive descent parser for the body of a formula (everything but hydrate separators and charge)
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    // Parses a sequence of elements and groups, stopping at the given closing bracket (or the end)
+    fn group(&mut self, closing: Option<char>) -> Option<Vec<(&'static Element, u32)>> {
+        let mut counts = Vec::new();
+
+        loop {
+            match self.peek() {
+                None if closing.is_none() => return Some(counts),
+                None => return None,
+                Some(c) if Some(c) == closing => {
+                    self.position += 1;
+                    return Some(counts);
+                }
+                Some(c @ ('(' | '[')) => {
+                    self.position += 1;
+                    let inner = self.group(Some(if c == '(' { ')' } else { ']' }))?;
+                    let multiplier = self.count()?;
+
+                    if inner.is_empty() {
+                        return None;
+                    }
+
+                    for (element, count) in inner {
+                        add_count(&mut counts, element, count.checked_mul(multiplier)?)?;
+                    }
+                }
+                Some(c) if c.is_ascii_uppercase() => {
+                    let element = self.element()?;
+                    let count = self.count()?;
+
+                    add_count(&mut counts, element, count)?;
+                }
+                Some(_) => return None,
+            }
+        }
+    }
+
+    // Parses an element symbol, preferring a two-letter symbol when one exists (e.g. "Co" over "C")
+    fn element(&mut self) -> Option<&'static Element> {
+        let first = self.chars[self.position];
+
+        if let Some(second) = self.chars.get(self.position + 1).filter(|c| c.is_ascii_lowercase()) {
+            if let Some(element) = Element::from_symbol(&format!("{}{}", first, second)) {
+                self.position += 2;
+                return Some(element);
+            }
+        }
+
+        self.position += 1;
+        Element::from_symbol(&first.to_string())
+    }
+
+    // Parses a count, which is 1 if no digits are written
+    // Returns None if the count is too large to hold
+    fn count(&mut self) -> Option<u32> {
+        let start = self.position;
+
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        match self.position - start {
+            0 => Some(1),
+            _ => self.chars[start..self.position].iter().collect::<String>().parse().ok(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+}
+
+// The molar mass of a formula, broken down by element
+pub struct MolarMass {
+    pub formula: Formula,
+}
+
+impl MolarMass {
+    // Gets the total molar mass (in g/mol)
+    pub fn total(&self) -> f64 {
+        self.formula.molar_mass()
+    }
+}
+
+impl From<&Formula> for MolarMass {
+    fn from(formula: &Formula) -> Self {
+        Self { formula: formula.clone() }
+    }
+}
+
+impl Display for MolarMass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "Molar mass of {}: {:.3} g/mol", self.formula, self.total())?;
+
+        let lines: Vec<String> = self.formula.counts.iter()
+            .map(|(element, count)| format!("    {}: {} × {} g/mol = {:.3} g/mol", element.symbol, count, element.atomic_weight, element.atomic_weight * *count as f64))
+            .collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+// The share of a formula's molar mass contributed by each element
+// Each value keeps the significant figures of the atomic weights it was found from
+pub struct PercentComposition {
+    pub formula: String,
+    pub molar_mass: Quantity<GramPerMole>,
+    pub mass_fractions: Vec<(&'static Element, Quantity<Dimensionless>)>,
+}
+
+impl PercentComposition {
+    // Returns None if the formula has no elements (e.g. e-)
+    pub fn new(formula: &Formula) -> Option<Self> {
+        let molar_mass = formula.measured_molar_mass()?;
+
+        Some(Self {
+            formula: formula.text.clone(),
+            molar_mass,
+            mass_fractions: formula.counts.iter()
+                .map(|(element, count)| (*element, atomic_weight(element) * *count as f64 / molar_mass))
+                .collect(),
+        })
+    }
+
+    // Returns None if the formula is invalid
+    pub fn prompt() -> Option<Self> {
+        Self::new(&Formula::parse(&read_string("Enter the formula (e.g. H2O, Ca3(PO4)2, CuSO4·5H2O): "))?)
+    }
+}
+
+impl Display for PercentComposition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "Percent composition of {} ({} g/mol)", self.formula, format_significant(self.molar_mass))?;
+
+        let lines: Vec<String> = self.mass_fractions.iter()
+            .map(|(element, mass_fraction)| format!(
+                "    {}: mass fraction {}, {} %",
+                element.symbol,
+                format_significant(*mass_fraction),
+                format_significant(*mass_fraction * 100.0),
+            ))
+            .collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+// Formats a quantity's value in plain decimal notation, rounded to its significant figures as {:.N$e} would be
+// Exact values are written in full
+fn format_significant<U>(quantity: Quantity<U>) -> String {
+    let (value, precision) = match quantity.significant_figures() {
+        Some(significant_figures) => (quantity.value(), significant_figures as usize - 1),
+        None => return quantity.value().to_string(),
+    };
+
+    // Take the power of ten after rounding, so that e.g. 99.996 to 4 significant figures carries over to 100.0
+    let magnitude = exponent(&format!("{:.1$e}", value, precision));
+    let decimals = (precision as i32 - magnitude).max(0) as usize;
+
+    format!("{:.1$}", value, decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(input: &str) -> Vec<(&'static str, u32)> {
+        Formula::parse(input).unwrap().counts.iter().map(|(element, count)| (element.symbol, *count)).collect()
+    }
+
+    #[test]
+    fn test_parse_formula() {
+        assert_eq!(counts("H2O"), [("H", 2), ("O", 1)]);
+        assert_eq!(counts("CO"), [("C", 1), ("O", 1)]);
+        assert_eq!(counts("Co"), [("Co", 1)]);
+        assert_eq!(counts("Ca3(PO4)2"), [("Ca", 3), ("P", 2), ("O", 8)]);
+        assert_eq!(counts("(CH3)2CO"), [("C", 3), ("H", 6), ("O", 1)]);
+        assert_eq!(counts("K4[Fe(CN)6]"), [("K", 4), ("Fe", 1), ("C", 6), ("N", 6)]);
+        assert_eq!(counts("CuSO4·5H2O"), [("Cu", 1), ("S", 1), ("O", 9), ("H", 10)]);
+        assert_eq!(counts("CaCl2*2H2O"), [("Ca", 1), ("Cl", 2), ("H", 4), ("O", 2)]);
+    }
+
+    #[test]
+    fn test_parse_charge() {
+        assert_eq!(Formula::parse("H2O").unwrap().charge, 0);
+        assert_eq!(Formula::parse("NH4+").unwrap().charge, 1);
+        assert_eq!(counts("NH4+"), [("N", 1), ("H", 4)]);
+        assert_eq!(Formula::parse("O2-").unwrap().charge, -2);
+        assert_eq!(Formula::parse("Hg2^2+").unwrap().charge, 2);
+        assert_eq!(Formula::parse("OH-").unwrap().charge, -1);
+        assert_eq!(Formula::parse("Fe3+").unwrap().charge, 3);
+        assert_eq!(Formula::parse("SO4^2-").unwrap().charge, -2);
+        assert_eq!(counts("SO4^2-"), [("S", 1), ("O", 4)]);
+        assert_eq!(Formula::parse("[Fe(CN)6]^3-").unwrap().charge, -3);
+        assert_eq!(Formula::parse("e-").map(|formula| formula.charge), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_formula() {
+        for input in ["", "h2o", "Xx2", "Ca3(PO4", "Ca3(PO4]2", "()", "5H2O", "H2O·", "H99999999999", "(H999999999)9", "CuSO4·999999999H2O99"] {
+            assert!(Formula::parse(input).is_none(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_molar_mass() {
+        assert!((Formula::parse("H2O").unwrap().molar_mass() - 18.015).abs() < 1e-9);
+        assert!((Formula::parse("CuSO4·5H2O").unwrap().molar_mass() - 249.677).abs() < 1e-9);
+        assert!((MolarMass::from(&Formula::parse("NaCl").unwrap()).total() - 58.44).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percent_composition() {
+        let composition = PercentComposition::new(&Formula::parse("H2O").unwrap()).unwrap();
+
+        // H (1.008) has 4 significant figures and O (15.999) has 5
+        assert_eq!(composition.to_string(), "Percent composition of H2O (18.015 g/mol)\n    H: mass fraction 0.1119, 11.19 %\n    O: mass fraction 0.88809, 88.809 %");
+        assert!((composition.mass_fractions.iter().map(|(_, mass_fraction)| mass_fraction.value()).sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_format_significant() {
+        assert_eq!(format_significant(Quantity::<GramPerMole>::measured(99.96, 4)), "99.96");
+        assert_eq!(format_significant(Quantity::<GramPerMole>::measured(99.996, 4)), "100.0");
+        assert_eq!(format_significant(Quantity::<GramPerMole>::measured(0.099996, 2)), "0.10");
+    }
+}
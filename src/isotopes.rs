@@ -0,0 +1,148 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::constants::*;
+use crate::readers::*;
+
+// The weighted average atomic mass of a mixture of isotopes
+// Uses the formula M = Σ(m × x), where
+    // M is the average atomic mass (u)
+    // m is the mass of each isotope (u)
+    // x is the fractional abundance of each isotope
+pub struct AverageAtomicMass {
+    // Each isotope as (label, mass, fractional abundance)
+    pub isotopes: Vec<(String, f64, f64)>,
+}
+
+impl AverageAtomicMass {
+    // Uses the natural isotopic composition of an element
+    // Returns None if no natural abundances are known for it
+    pub fn from_element(element: &Element) -> Option<Self> {
+        let isotopes: Vec<(String, f64, f64)> = element.isotopes()
+            .filter(|isotope| isotope.abundance > 0.0)
+            .map(|isotope| (format!("{}-{}", element.symbol, isotope.mass_number), isotope.mass, isotope.abundance))
+            .collect();
+
+        (!isotopes.is_empty()).then_some(Self { isotopes })
+    }
+
+    // Returns None if the element has no natural abundances or no isotopes were entered
+    pub fn prompt() -> Option<Self> {
+        match read_i32("Enter 1 to use an element's natural isotopes or 2 to enter the isotopes: ") {
+            1 => Self::from_element(Element::lookup(&read_string("Enter the element symbol, name or atomic number: "))?),
+            _ => {
+                let count = read_i32("Enter the number of isotopes: ");
+                let isotopes: Vec<(String, f64, f64)> = (1..=count)
+                    .map(|index| {
+                        let mass = read_f64(&format!("Enter the mass of isotope {} (in u): ", index));
+                        let abundance = read_f64(&format!("Enter the abundance of isotope {} (in %): ", index)) / 100.0;
+                        (format!("Isotope {}", index), mass, abundance)
+                    })
+                    .collect();
+
+                (!isotopes.is_empty()).then_some(Self { isotopes })
+            }
+        }
+    }
+
+    pub fn total_abundance(&self) -> f64 {
+        self.isotopes.iter().map(|(_, _, abundance)| abundance).sum()
+    }
+
+    pub fn average(&self) -> f64 {
+        self.isotopes.iter().map(|(_, mass, abundance)| mass * abundance).sum()
+    }
+}
+
+impl Display for AverageAtomicMass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let width = self.isotopes.iter().map(|(label, _, _)| label.chars().count()).max().unwrap_or(0);
+
+        for (label, mass, abundance) in &self.isotopes {
+            writeln!(f, "{:<4$}  {} u × {}% = {:.4} u", label, mass, abundance * 100.0, mass * abundance, width)?;
+        }
+
+        // Abundances are still used as given, but the result is only meaningful if they cover the whole sample
+        if (self.total_abundance() - 1.0).abs() > 1e-3 {
+            writeln!(f, "Warning: abundances add up to {}%, not 100%", self.total_abundance() * 100.0)?;
+        }
+
+        write!(f, "Average atomic mass: {:.4} u", self.average())
+    }
+}
+
+// Solves for the abundances of a two-isotope element from its average atomic mass
+// Uses the formula M = m₁x + m₂(1 - x), where
+    // M is the average atomic mass (u)
+    // m₁ and m₂ are the masses of the two isotopes (u)
+    // x is the fractional abundance of the first isotope
+// Returns the abundances of the first and second isotopes, or None if the average isn't between the two masses
+pub fn two_isotope_abundances(average: f64, first_mass: f64, second_mass: f64) -> Option<(f64, f64)> {
+    if first_mass == second_mass {
+        return None;
+    }
+
+    let first_abundance = (average - second_mass) / (first_mass - second_mass);
+
+    (0.0..=1.0).contains(&first_abundance).then_some((first_abundance, 1.0 - first_abundance))
+}
+
+// Reads an average atomic mass and two isotope masses, then solves for the abundances
+pub fn calculate_two_isotope_abundances() -> String {
+    let average = read_f64("Enter the average atomic mass (in u): ");
+    let first_mass = read_f64("Enter the mass of the first isotope (in u): ");
+    let second_mass = read_f64("Enter the mass of the second isotope (in u): ");
+
+    match two_isotope_abundances(average, first_mass, second_mass) {
+        Some((first, second)) => format!(
+            "{} = {} × x + {} × (1 - x)\nFirst isotope:  {:.4}%\nSecond isotope: {:.4}%",
+            average, first_mass, second_mass, first * 100.0, second * 100.0,
+        ),
+        None => String::from("The average atomic mass must lie between the two isotope masses."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_average() {
+        let cases = [("Cl", 35.453), ("Cu", 63.546), ("Mg", 24.305), ("Fe", 55.845), ("U", 238.029)];
+
+        for (symbol, expected) in cases {
+            let average = AverageAtomicMass::from_element(Element::from_symbol(symbol).unwrap()).unwrap().average();
+            assert!((average - expected).abs() < 2e-3, "{}: {}", symbol, average);
+        }
+
+        assert!(AverageAtomicMass::from_element(Element::from_symbol("Pu").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_heavy_natural_average() {
+        // Elements with many isotopes, where the tabulated weights allow for natural variation
+        let cases = [("Ga", 69.723), ("Sr", 87.62), ("Sn", 118.71), ("Xe", 131.293), ("Ba", 137.327), ("Gd", 157.25), ("Hg", 200.59)];
+
+        for (symbol, expected) in cases {
+            let average = AverageAtomicMass::from_element(Element::from_symbol(symbol).unwrap()).unwrap().average();
+            assert!((average - expected).abs() < 1e-2, "{}: {}", symbol, average);
+        }
+    }
+
+    #[test]
+    fn test_entered_isotopes() {
+        let mixture = AverageAtomicMass { isotopes: vec![(String::from("A"), 10.0, 0.25), (String::from("B"), 12.0, 0.75)] };
+
+        assert!((mixture.average() - 11.5).abs() < 1e-9);
+        assert!(!mixture.to_string().contains("Warning"));
+    }
+
+    #[test]
+    fn test_two_isotope_abundances() {
+        let (copper_63, copper_65) = two_isotope_abundances(63.546, 62.9295975, 64.9277895).unwrap();
+        assert!((copper_63 - 0.6915).abs() < 1e-3);
+        assert!((copper_65 - 0.3085).abs() < 1e-3);
+
+        assert_eq!(two_isotope_abundances(66.0, 62.93, 64.93), None);
+        assert_eq!(two_isotope_abundances(63.0, 63.0, 63.0), None);
+    }
+}
@@ -0,0 +1,16 @@
+#![allow(mixed_script_confusables)]
+
+pub mod configuration;
+pub mod constants;
+pub mod empirical;
+pub mod equation;
+pub mod formula;
+pub mod isotopes;
+pub mod measurements;
+pub mod periodic;
+pub mod quantity;
+pub mod readers;
+pub mod slater;
+pub mod stoichiometry;
+pub mod terms;
+pub mod trends;
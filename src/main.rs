@@ -1,14 +1,18 @@
-#![allow(dead_code)]
 #![allow(mixed_script_confusables)]
 
-use std::fmt::{Display, Formatter};
-
-mod constants;
-mod measurements;
-mod readers;
-
-use readers::*;
-use measurements::*;
+use chem::constants;
+use chem::readers::*;
+use chem::measurements::*;
+use chem::configuration::*;
+use chem::formula::*;
+use chem::empirical::*;
+use chem::equation::*;
+use chem::isotopes::*;
+use chem::periodic::*;
+use chem::slater::*;
+use chem::stoichiometry::*;
+use chem::terms::*;
+use chem::trends::*;
 
 fn main() {
     // Give the user a menu of calculations to choose from
@@ -22,25 +26,125 @@ fn main() {
     println!("7. Frequency              -> Energy");
     println!("8. Wavelength             -> Energy");
     println!("9. Element                -> Electron Configuration");
+    println!("10. Ion                   -> Electron Configuration");
+    println!("11. Element or Ion        -> Magnetic Properties");
+    println!("12. Element or Ion        -> Quantum Numbers");
+    println!("13. Quantum Numbers       -> Validity");
+    println!("14. Element or Ion        -> Effective Nuclear Charge");
+    println!("15. Element or Ion        -> Ground State Term Symbol");
+    println!("16. Open Subshell         -> Microstates and Terms");
+    println!("17. Element               -> Properties");
+    println!("18. Formula               -> Molar Mass");
+    println!("19. Formula               -> Percent Composition");
+    println!("20. Composition           -> Empirical Formula");
+    println!("21. Equation              -> Balanced Equation");
+    println!("22. Equation              -> Stoichiometry");
+    println!("23. Equation              -> Limiting Reactant and Yield");
+    println!("24. Periodic Table");
+    println!("25. Two Elements          -> Periodic Trends");
+    println!("26. Isotopes              -> Average Atomic Mass");
+    println!("27. Average Atomic Mass   -> Isotope Abundances");
 
     let choice = read_i32("Enter menu selection: ");
 
     println!("{}", match choice {
         1 => calculate_electronic_transition_energy(),
-        2 => Frequency::from(WorkFunction::prompt()).to_string(),
-        3 => Wavelength::from(WorkFunction::prompt()).to_string(),
-        4 => WorkFunction::from(Frequency::prompt()).to_string(),
-        5 => Wavelength::from(Frequency::prompt()).to_string(),
-        6 => Frequency::from(Wavelength::prompt()).to_string(),
-        7 => Energy::from(Frequency::prompt()).to_string(),
-        8 => Energy::from(Wavelength::prompt()).to_string(),
+        2 => prompt_units(Frequency::from(WorkFunction::prompt())),
+        3 => prompt_units(Wavelength::from(WorkFunction::prompt())),
+        4 => prompt_units(WorkFunction::from(Frequency::prompt())),
+        5 => prompt_units(Wavelength::from(Frequency::prompt())),
+        6 => prompt_units(Frequency::from(Wavelength::prompt())),
+        7 => prompt_units(Energy::from(Frequency::prompt())),
+        8 => prompt_units(Energy::from(Wavelength::prompt())),
         9 => {
             let element = read_string("Enter the element symbol: ");
-            match get_electron_configuration(&element) {
-                Some(config) => config,
-                None => String::from("Invalid element symbol."),
+            prompt_electron_configuration(&element, 0)
+        }
+        10 => match read_species("Enter the ion (e.g. Fe3+, O2-): ") {
+            Some((element, charge)) => prompt_electron_configuration(&element, charge),
+            None => String::from("Invalid ion."),
+        },
+        11 => match read_species("Enter the element or ion (e.g. Fe, Fe3+, O2-): ") {
+            Some((element, charge)) => match get_electron_configuration(&element, charge, SubshellOrder::Energy, ConfigurationOutput::Magnetism) {
+                Some((magnetism, _)) => magnetism,
+                None => String::from("Invalid element symbol or charge."),
+            },
+            None => String::from("Invalid element or ion."),
+        },
+        12 => match read_species("Enter the element or ion (e.g. Fe, Fe3+, O2-): ") {
+            Some((element, charge)) => {
+                let output = match read_i32("Enter 1 to list every electron or 2 for only the last (differentiating) electron: ") {
+                    2 => ConfigurationOutput::LastElectron,
+                    _ => ConfigurationOutput::QuantumNumbers,
+                };
+
+                match get_electron_configuration(&element, charge, SubshellOrder::Energy, output) {
+                    Some((quantum_numbers, _)) => quantum_numbers,
+                    None => String::from("Invalid element symbol or charge."),
+                }
+            }
+            None => String::from("Invalid element or ion."),
+        },
+        13 => {
+            let quantum_numbers = QuantumNumbers::prompt();
+            match quantum_numbers.problem() {
+                Some(problem) => format!("({}) is not allowed: {}", quantum_numbers, problem),
+                None => format!("({}) is allowed", quantum_numbers),
             }
         }
+        14 => calculate_effective_nuclear_charge(),
+        15 => match read_species("Enter the element or ion (e.g. Fe, Fe3+, O2-): ") {
+            Some((element, charge)) => match ElectronConfiguration::ion(&element, charge) {
+                Some(config) => {
+                    let (term, source) = ground_state_term(&config);
+                    format!("{} ({}, {})", term, config, source)
+                }
+                None => String::from("Invalid element symbol or charge."),
+            },
+            None => String::from("Invalid element or ion."),
+        },
+        16 => list_terms(),
+        17 => match constants::Element::lookup(&read_string("Enter the element symbol, name or atomic number: ")) {
+            Some(element) => element.to_string(),
+            None => String::from("Invalid element."),
+        },
+        18 => match Formula::parse(&read_string("Enter the formula (e.g. H2O, Ca3(PO4)2, CuSO4·5H2O): ")) {
+            Some(formula) => MolarMass::from(&formula).to_string(),
+            None => String::from("Invalid formula."),
+        },
+        19 => match PercentComposition::prompt() {
+            Some(composition) => composition.to_string(),
+            None => String::from("Invalid formula."),
+        },
+        20 => match EmpiricalFormula::prompt() {
+            Some(empirical_formula) => empirical_formula.to_string(),
+            None => String::from("Could not find a whole number ratio from the given composition."),
+        },
+        21 => match Reaction::parse(&read_string("Enter the equation (e.g. C3H8 + O2 -> CO2 + H2O): ")) {
+            Some(reaction) => reaction.balance().to_string(),
+            None => String::from("Invalid equation."),
+        },
+        22 => match Stoichiometry::prompt() {
+            Some(stoichiometry) => stoichiometry.to_string(),
+            None => String::from("Invalid or unbalanceable equation, or the species is not in it."),
+        },
+        23 => match LimitingReactant::prompt() {
+            Some(limiting_reactant) => limiting_reactant.to_string(),
+            None => String::from("Invalid or unbalanceable equation, or the product is not in it."),
+        },
+        24 => match Highlight::prompt() {
+            Some(highlight) => periodic_table(&highlight),
+            None => String::from("Invalid element, block or group."),
+        },
+        25 => match ElementComparison::prompt() {
+            Some(comparison) => comparison.to_string(),
+            None => String::from("Invalid element."),
+        },
+        26 => match AverageAtomicMass::prompt() {
+            Some(average) => average.to_string(),
+            None => String::from("Invalid element, or no natural isotopes are known for it."),
+        },
+        27 => calculate_two_isotope_abundances(),
         _ => String::from("Invalid choice, try again."),
     });
 }
@@ -50,190 +154,276 @@ fn main() {
 pub fn calculate_electronic_transition_energy() -> String {
     let n = read_i32("Enter the first energy level (n): ");
     let m = read_i32("Enter the second energy level (m): ");
-    let significant_figures = prompt_sigfigs();
 
+    // The levels are exact, so the result keeps the significant figures of the Rydberg energy
     let energy_release = constants::RYDBERG * (1.0 / (n * n) as f64 - 1.0 / (m * m) as f64);
 
-    format!("The energy released is {:.1$e} J", energy_release, significant_figures)
+    prompt_units(Energy::new(energy_release))
 }
 
-// Takes an atomic number and returns the electron configuration as a string
-fn get_electron_configuration(element: &str) -> Option<String> {
-    enum ShellType {
-        S,
-        P,
-        D,
-        F,
-    }
+// Asks for the notation and order, then formats the electron configuration of the given element or ion
+fn prompt_electron_configuration(element: &str, charge: i32) -> String {
+    let output = ConfigurationOutput::prompt();
+    let order = SubshellOrder::prompt();
 
-    struct Shell {
-        shell_type: ShellType,
-        electrons: u32,
+    match get_electron_configuration(element, charge, order, output) {
+        Some((config, source)) => format!("{} ({})", config, source),
+        None => String::from("Invalid element symbol or charge."),
     }
+}
 
-    impl Shell {
-        fn new(shell_type: ShellType) -> Self {
-            Self {
-                shell_type,
-                electrons: 0,
-            }
-        }
-
-        // Gets all possible shells for a given n, and populates them with electrons
-        fn from_principle_value(n: u32, remaining_electrons: &mut u32) -> Vec<Shell> {
-            let mut shells = Self::get_possible_shells(n);
-
-            for shell in &mut shells {
-                let max_electrons = shell.max_electrons();
-
-                if *remaining_electrons >= max_electrons {
-                    shell.electrons = max_electrons;
-                    *remaining_electrons -= max_electrons;
-                } else {
-                    shell.electrons = *remaining_electrons;
-                    *remaining_electrons = 0;
-                }
-            }
-
-            shells
-        }
-
-        // Gets all possible shells for a given n
-        fn get_possible_shells(n: u32) -> Vec<Shell> {
-            use ShellType::*;
+// Uses Slater's rules to calculate the shielding and effective nuclear charge felt by an electron
+fn calculate_effective_nuclear_charge() -> String {
+    let (element, charge) = match read_species("Enter the element or ion (e.g. Fe, Fe3+, O2-): ") {
+        Some(species) => species,
+        None => return String::from("Invalid element or ion."),
+    };
+
+    let config = match ElectronConfiguration::ion(&element, charge) {
+        Some(config) => config,
+        None => return String::from("Invalid element symbol or charge."),
+    };
+
+    let (n, shell_type) = match read_subshell("Enter the subshell of the electron (e.g. 2p, 3d): ") {
+        Some(subshell) => subshell,
+        None => return String::from("Invalid subshell."),
+    };
+
+    match SlaterShielding::new(&config, n, shell_type) {
+        Some(shielding) => shielding.to_string(),
+        None => format!("There are no electrons in the {}{} subshell.", n, shell_type.letter()),
+    }
+}
 
-            let mut shells: Vec<Shell> = Vec::new();
+// Enumerates the microstates of an open subshell, then lists the terms and levels that arise from them
+fn list_terms() -> String {
+    let (shell_type, electrons) = match read_subshell_occupancy("Enter the open subshell (e.g. p2, d3): ") {
+        Some(occupancy) => occupancy,
+        None => return String::from("Invalid subshell."),
+    };
 
-            if n > 0 {
-                shells.push(Shell::new(S));
-            }
+    let mut lines = vec![format!("{}{}: {} microstates", shell_type.letter(), electrons, microstates(shell_type.l(), electrons).len())];
 
-            if n > 1 {
-                shells.push(Shell::new(P));
-            }
-
-            if n > 2 {
-                shells.push(Shell::new(D));
-            }
+    for term in terms(shell_type.l(), electrons) {
+        lines.push(term.levels().iter().map(TermSymbol::to_string).collect::<Vec<_>>().join(" "));
+    }
 
-            if n > 3 {
-                shells.push(Shell::new(F));
-            }
+    lines.join("\n")
+}
 
-            shells
-        }
+// Takes an element and returns its ground state electron configuration as a string
+// A nonzero charge gives the configuration of the ion, starting from the neutral atom's configuration
+fn get_electron_configuration(element: &str, charge: i32, order: SubshellOrder, output: ConfigurationOutput) -> Option<(String, ConfigurationSource)> {
+    ElectronConfiguration::ion(element, charge).map(|config| (config.format(order, output), config.source()))
+}
 
-        // Gets the maximum number of electrons for a given shell type
-        fn max_electrons(&self) -> u32 {
-            use ShellType::*;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::ConfigurationSource::*;
 
-            match self.shell_type {
-                S => 2,
-                P => 6,
-                D => 10,
-                F => 14,
-            }
-        }
+    #[test]
+    fn test_electron_configuration() {
+        assert_eq!(get_electron_configuration("H", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s1"), Predicted)));
+        assert_eq!(get_electron_configuration("He", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Li", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Be", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2"), Predicted)));
+        assert_eq!(get_electron_configuration("B", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p1"), Predicted)));
+        assert_eq!(get_electron_configuration("C", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p2"), Predicted)));
+        assert_eq!(get_electron_configuration("N", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p3"), Predicted)));
+        assert_eq!(get_electron_configuration("O", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p4"), Predicted)));
+        assert_eq!(get_electron_configuration("F", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p5"), Predicted)));
+        assert_eq!(get_electron_configuration("Ne", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Na", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Mg", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Al", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p1"), Predicted)));
+        assert_eq!(get_electron_configuration("Si", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p2"), Predicted)));
+        assert_eq!(get_electron_configuration("P", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p3"), Predicted)));
+        assert_eq!(get_electron_configuration("S", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p4"), Predicted)));
+        assert_eq!(get_electron_configuration("Cl", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p5"), Predicted)));
+        assert_eq!(get_electron_configuration("Ar", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6"), Predicted)));
+        assert_eq!(get_electron_configuration("K", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Ca", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Sc", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d1"), Predicted)));
+        assert_eq!(get_electron_configuration("Ti", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d2"), Predicted)));
+        assert_eq!(get_electron_configuration("V", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d3"), Predicted)));
+        assert_eq!(get_electron_configuration("Cr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s1 3d5"), Experimental)));
+        assert_eq!(get_electron_configuration("Mn", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d5"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d6"), Predicted)));
+        assert_eq!(get_electron_configuration("Co", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d7"), Predicted)));
+        assert_eq!(get_electron_configuration("Ni", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d8"), Predicted)));
+        assert_eq!(get_electron_configuration("Cu", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s1 3d10"), Experimental)));
+        assert_eq!(get_electron_configuration("Zn", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10"), Predicted)));
+        assert_eq!(get_electron_configuration("Ga", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p1"), Predicted)));
+        assert_eq!(get_electron_configuration("Ge", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p2"), Predicted)));
+        assert_eq!(get_electron_configuration("As", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p3"), Predicted)));
+        assert_eq!(get_electron_configuration("Se", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p4"), Predicted)));
+        assert_eq!(get_electron_configuration("Br", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p5"), Predicted)));
+        assert_eq!(get_electron_configuration("Kr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Rb", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Sr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Y", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d1"), Predicted)));
+        assert_eq!(get_electron_configuration("Zr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d2"), Predicted)));
+        assert_eq!(get_electron_configuration("Nb", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s1 4d4"), Experimental)));
+        assert_eq!(get_electron_configuration("Mo", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s1 4d5"), Experimental)));
+        assert_eq!(get_electron_configuration("Tc", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d5"), Predicted)));
+        assert_eq!(get_electron_configuration("Ru", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s1 4d7"), Experimental)));
+        assert_eq!(get_electron_configuration("Rh", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s1 4d8"), Experimental)));
+        assert_eq!(get_electron_configuration("Pd", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 4d10"), Experimental)));
+        assert_eq!(get_electron_configuration("Ag", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s1 4d10"), Experimental)));
+        assert_eq!(get_electron_configuration("Cd", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10"), Predicted)));
+        assert_eq!(get_electron_configuration("In", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p1"), Predicted)));
+        assert_eq!(get_electron_configuration("Sn", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p2"), Predicted)));
+        assert_eq!(get_electron_configuration("Sb", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p3"), Predicted)));
+        assert_eq!(get_electron_configuration("Te", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p4"), Predicted)));
+        assert_eq!(get_electron_configuration("I", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p5"), Predicted)));
+        assert_eq!(get_electron_configuration("Xe", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Cs", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Ba", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2"), Predicted)));
+        assert_eq!(get_electron_configuration("La", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 5d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Ce", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f1 5d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Pr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f3"), Predicted)));
+        assert_eq!(get_electron_configuration("Nd", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f4"), Predicted)));
+        assert_eq!(get_electron_configuration("Pm", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f5"), Predicted)));
+        assert_eq!(get_electron_configuration("Sm", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f6"), Predicted)));
+        assert_eq!(get_electron_configuration("Eu", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f7"), Predicted)));
+        assert_eq!(get_electron_configuration("Gd", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f7 5d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Tb", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f9"), Predicted)));
+        assert_eq!(get_electron_configuration("Dy", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f10"), Predicted)));
+        assert_eq!(get_electron_configuration("Ho", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f11"), Predicted)));
+        assert_eq!(get_electron_configuration("Er", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f12"), Predicted)));
+        assert_eq!(get_electron_configuration("Tm", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f13"), Predicted)));
+        assert_eq!(get_electron_configuration("Yb", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14"), Predicted)));
+        assert_eq!(get_electron_configuration("Lu", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d1"), Predicted)));
+        assert_eq!(get_electron_configuration("Hf", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d2"), Predicted)));
+        assert_eq!(get_electron_configuration("Ta", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d3"), Predicted)));
+        assert_eq!(get_electron_configuration("W", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d4"), Predicted)));
+        assert_eq!(get_electron_configuration("Re", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d5"), Predicted)));
+        assert_eq!(get_electron_configuration("Os", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d6"), Predicted)));
+        assert_eq!(get_electron_configuration("Ir", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d7"), Predicted)));
+        assert_eq!(get_electron_configuration("Pt", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s1 4f14 5d9"), Experimental)));
+        assert_eq!(get_electron_configuration("Au", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s1 4f14 5d10"), Experimental)));
+        assert_eq!(get_electron_configuration("Hg", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10"), Predicted)));
+        assert_eq!(get_electron_configuration("Tl", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p1"), Predicted)));
+        assert_eq!(get_electron_configuration("Pb", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p2"), Predicted)));
+        assert_eq!(get_electron_configuration("Bi", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p3"), Predicted)));
+        assert_eq!(get_electron_configuration("Po", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p4"), Predicted)));
+        assert_eq!(get_electron_configuration("At", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p5"), Predicted)));
+        assert_eq!(get_electron_configuration("Rn", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Fr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Ra", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Ac", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 6d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Th", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 6d2"), Experimental)));
+        assert_eq!(get_electron_configuration("Pa", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f2 6d1"), Experimental)));
+        assert_eq!(get_electron_configuration("U", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f3 6d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Np", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f4 6d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Pu", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f6"), Predicted)));
+        assert_eq!(get_electron_configuration("Am", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f7"), Predicted)));
+        assert_eq!(get_electron_configuration("Cm", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f7 6d1"), Experimental)));
+        assert_eq!(get_electron_configuration("Bk", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f9"), Predicted)));
+        assert_eq!(get_electron_configuration("Cf", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f10"), Predicted)));
+        assert_eq!(get_electron_configuration("Es", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f11"), Predicted)));
+        assert_eq!(get_electron_configuration("Fm", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f12"), Predicted)));
+        assert_eq!(get_electron_configuration("Md", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f13"), Predicted)));
+        assert_eq!(get_electron_configuration("No", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14"), Predicted)));
+        assert_eq!(get_electron_configuration("Lr", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 7p1"), Experimental)));
+        assert_eq!(get_electron_configuration("Rf", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d2"), Predicted)));
+        assert_eq!(get_electron_configuration("Db", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d3"), Predicted)));
+        assert_eq!(get_electron_configuration("Sg", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d4"), Predicted)));
+        assert_eq!(get_electron_configuration("Bh", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d5"), Predicted)));
+        assert_eq!(get_electron_configuration("Hs", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d6"), Predicted)));
+        assert_eq!(get_electron_configuration("Mt", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d7"), Predicted)));
+        assert_eq!(get_electron_configuration("Ds", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d8"), Predicted)));
+        assert_eq!(get_electron_configuration("Rg", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d9"), Predicted)));
+        assert_eq!(get_electron_configuration("Cn", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10"), Predicted)));
+        assert_eq!(get_electron_configuration("Nh", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10 7p1"), Predicted)));
+        assert_eq!(get_electron_configuration("Fl", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10 7p2"), Predicted)));
+        assert_eq!(get_electron_configuration("Mc", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10 7p3"), Predicted)));
+        assert_eq!(get_electron_configuration("Lv", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10 7p4"), Predicted)));
+        assert_eq!(get_electron_configuration("Ts", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10 7p5"), Predicted)));
+        assert_eq!(get_electron_configuration("Og", 0, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 4s2 3d10 4p6 5s2 4d10 5p6 6s2 4f14 5d10 6p6 7s2 5f14 6d10 7p6"), Predicted)));
     }
 
-    impl Display for Shell {
-        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{}{}", match self.shell_type {
-                ShellType::S => "s",
-                ShellType::P => "p",
-                ShellType::D => "d",
-                ShellType::F => "f",
-            }, self.electrons)
-        }
+    #[test]
+    fn test_electron_configuration_shell_order() {
+        assert_eq!(get_electron_configuration("Sc", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d1 4s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d6 4s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Zn", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d10 4s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Ga", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d10 4s2 4p1"), Predicted)));
+        assert_eq!(get_electron_configuration("Kr", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d10 4s2 4p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Hf", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d10 4s2 4p6 4d10 4f14 5s2 5p6 5d2 6s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Og", 0, SubshellOrder::Shell, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6 3s2 3p6 3d10 4s2 4p6 4d10 4f14 5s2 5p6 5d10 5f14 6s2 6p6 6d10 7s2 7p6"), Predicted)));
     }
 
-    // Represents all the shells in a given energy level (n)
-    struct EnergyLevel {
-        n: u32,
-        shells: Vec<Shell>,
+    #[test]
+    fn test_electron_configuration_condensed() {
+        assert_eq!(get_electron_configuration("H", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("1s1"), Predicted)));
+        assert_eq!(get_electron_configuration("He", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("1s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Na", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ne] 3s1"), Predicted)));
+        assert_eq!(get_electron_configuration("Ar", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ne] 3s2 3p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ar] 4s2 3d6"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 0, SubshellOrder::Shell, ConfigurationOutput::Condensed), Some((String::from("[Ar] 3d6 4s2"), Predicted)));
+        assert_eq!(get_electron_configuration("Cu", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ar] 4s1 3d10"), Experimental)));
+        assert_eq!(get_electron_configuration("Pd", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Kr] 4d10"), Experimental)));
+        assert_eq!(get_electron_configuration("Pb", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Xe] 6s2 4f14 5d10 6p2"), Predicted)));
+        assert_eq!(get_electron_configuration("Og", 0, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Rn] 7s2 5f14 6d10 7p6"), Predicted)));
     }
 
-    impl EnergyLevel {
-        fn new(n: u32, remaining_electrons: &mut u32) -> Self {
-            Self {
-                n,
-                shells: Shell::from_principle_value(n, remaining_electrons),
-            }
-        }
+    #[test]
+    fn test_electron_configuration_ions() {
+        assert_eq!(get_electron_configuration("Na", 1, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6"), Predicted)));
+        assert_eq!(get_electron_configuration("O", -2, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2 2s2 2p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Cl", -1, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ne] 3s2 3p6"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 2, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ar] 3d6"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 3, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ar] 3d5"), Predicted)));
+        assert_eq!(get_electron_configuration("Cu", 1, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ar] 3d10"), Predicted)));
+        assert_eq!(get_electron_configuration("Cu", 2, SubshellOrder::Energy, ConfigurationOutput::Condensed), Some((String::from("[Ar] 3d9"), Predicted)));
+        assert_eq!(get_electron_configuration("Ga", 3, SubshellOrder::Shell, ConfigurationOutput::Condensed), Some((String::from("[Ar] 3d10"), Predicted)));
+        assert_eq!(get_electron_configuration("Sn", 2, SubshellOrder::Shell, ConfigurationOutput::Condensed), Some((String::from("[Kr] 4d10 5s2"), Predicted)));
+        assert_eq!(get_electron_configuration("H", -1, SubshellOrder::Energy, ConfigurationOutput::Full), Some((String::from("1s2"), Predicted)));
+        assert_eq!(get_electron_configuration("H", 2, SubshellOrder::Energy, ConfigurationOutput::Full), None);
     }
 
-    impl Display for EnergyLevel {
-        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            let mut shell_strings = Vec::new();
-
-            for shell in &self.shells {
-                if shell.electrons > 0 {
-                    shell_strings.push(format!("{}{}", self.n, shell));
-                }
-            }
-
-            write!(f, "{}", shell_strings.join(" "))
-        }
+    #[test]
+    fn test_orbital_diagram() {
+        assert_eq!(get_electron_configuration("H", 0, SubshellOrder::Energy, ConfigurationOutput::OrbitalDiagram), Some((String::from("1s [↑ ]\nUnpaired electrons: 1"), Predicted)));
+        assert_eq!(get_electron_configuration("N", 0, SubshellOrder::Energy, ConfigurationOutput::OrbitalDiagram), Some((String::from("1s [↑↓]\n2s [↑↓]\n2p [↑ ][↑ ][↑ ]\nUnpaired electrons: 3"), Predicted)));
+        assert_eq!(get_electron_configuration("O", 0, SubshellOrder::Energy, ConfigurationOutput::OrbitalDiagram), Some((String::from("1s [↑↓]\n2s [↑↓]\n2p [↑↓][↑ ][↑ ]\nUnpaired electrons: 2"), Predicted)));
+        assert_eq!(get_electron_configuration("Ne", 0, SubshellOrder::Energy, ConfigurationOutput::OrbitalDiagram), Some((String::from("1s [↑↓]\n2s [↑↓]\n2p [↑↓][↑↓][↑↓]\nUnpaired electrons: 0"), Predicted)));
+        assert_eq!(get_electron_configuration("Cr", 0, SubshellOrder::Shell, ConfigurationOutput::OrbitalDiagram), Some((String::from("1s [↑↓]\n2s [↑↓]\n2p [↑↓][↑↓][↑↓]\n3s [↑↓]\n3p [↑↓][↑↓][↑↓]\n3d [↑ ][↑ ][↑ ][↑ ][↑ ]\n4s [↑ ]\nUnpaired electrons: 6"), Experimental)));
+        assert_eq!(get_electron_configuration("Fe", 2, SubshellOrder::Energy, ConfigurationOutput::OrbitalDiagram), Some((String::from("1s [↑↓]\n2s [↑↓]\n2p [↑↓][↑↓][↑↓]\n3s [↑↓]\n3p [↑↓][↑↓][↑↓]\n3d [↑↓][↑ ][↑ ][↑ ][↑ ]\nUnpaired electrons: 4"), Predicted)));
     }
 
-    // Represents all the energy levels in an element's ground state
-    struct ElectronConfiguration {
-        energy_levels: Vec<EnergyLevel>,
+    #[test]
+    fn test_magnetism() {
+        assert_eq!(get_electron_configuration("He", 0, SubshellOrder::Energy, ConfigurationOutput::Magnetism), Some((String::from("Unpaired electrons: 0\nDiamagnetic\nSpin-only magnetic moment: 0.00 μB"), Predicted)));
+        assert_eq!(get_electron_configuration("O", 0, SubshellOrder::Energy, ConfigurationOutput::Magnetism), Some((String::from("Unpaired electrons: 2\nParamagnetic\nSpin-only magnetic moment: 2.83 μB"), Predicted)));
+        assert_eq!(get_electron_configuration("Fe", 3, SubshellOrder::Energy, ConfigurationOutput::Magnetism), Some((String::from("Unpaired electrons: 5\nParamagnetic\nSpin-only magnetic moment: 5.92 μB"), Predicted)));
+        assert_eq!(get_electron_configuration("Zn", 2, SubshellOrder::Energy, ConfigurationOutput::Magnetism), Some((String::from("Unpaired electrons: 0\nDiamagnetic\nSpin-only magnetic moment: 0.00 μB"), Predicted)));
+        assert_eq!(get_electron_configuration("Cu", 2, SubshellOrder::Energy, ConfigurationOutput::Magnetism), Some((String::from("Unpaired electrons: 1\nParamagnetic\nSpin-only magnetic moment: 1.73 μB"), Predicted)));
     }
 
-    impl ElectronConfiguration {
-        fn new(element: &str) -> Self {
-            let mut electrons_remaining = constants::atomic_number(element).expect("Invalid element used");
-            let mut energy_levels: Vec<EnergyLevel> = Vec::new();
-            let mut n = 1;
-
-            while electrons_remaining > 0 {
-                energy_levels.push(EnergyLevel::new(n, &mut electrons_remaining));
-                n += 1;
-            }
-
-            Self {
-                energy_levels,
-            }
-        }
+    #[test]
+    fn test_quantum_numbers() {
+        assert_eq!(get_electron_configuration("B", 0, SubshellOrder::Energy, ConfigurationOutput::QuantumNumbers), Some((String::from("1s: n = 1, l = 0, mℓ = 0, mₛ = +½\n1s: n = 1, l = 0, mℓ = 0, mₛ = -½\n2s: n = 2, l = 0, mℓ = 0, mₛ = +½\n2s: n = 2, l = 0, mℓ = 0, mₛ = -½\n2p: n = 2, l = 1, mℓ = -1, mₛ = +½"), Predicted)));
+        assert_eq!(get_electron_configuration("O", 0, SubshellOrder::Energy, ConfigurationOutput::LastElectron), Some((String::from("2p: n = 2, l = 1, mℓ = -1, mₛ = -½"), Predicted)));
+        assert_eq!(get_electron_configuration("Sc", 0, SubshellOrder::Energy, ConfigurationOutput::LastElectron), Some((String::from("3d: n = 3, l = 2, mℓ = -2, mₛ = +½"), Predicted)));
+        assert_eq!(get_electron_configuration("Cr", 0, SubshellOrder::Energy, ConfigurationOutput::LastElectron), Some((String::from("3d: n = 3, l = 2, mℓ = 2, mₛ = +½"), Experimental)));
+        assert_eq!(get_electron_configuration("H", 1, SubshellOrder::Energy, ConfigurationOutput::LastElectron), Some((String::from("No electrons"), Predicted)));
     }
 
-    impl Display for ElectronConfiguration {
-        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            let mut energy_level_strings = Vec::new();
-
-            for energy_level in &self.energy_levels {
-                energy_level_strings.push(format!("{}", energy_level));
-            }
-
-            write!(f, "{}", energy_level_strings.join(" "))
-        }
+    #[test]
+    fn test_quantum_number_validity() {
+        assert_eq!(QuantumNumbers { n: 2, l: 1, ml: -1, ms: 0.5 }.problem(), None);
+        assert_eq!(QuantumNumbers { n: 3, l: 2, ml: 2, ms: -0.5 }.problem(), None);
+        assert_eq!(QuantumNumbers { n: 0, l: 0, ml: 0, ms: 0.5 }.problem(), Some(String::from("n must be a positive integer, but is 0")));
+        assert_eq!(QuantumNumbers { n: 2, l: 2, ml: 0, ms: 0.5 }.problem(), Some(String::from("l must be between 0 and n - 1 (1), but is 2")));
+        assert_eq!(QuantumNumbers { n: 3, l: 1, ml: -2, ms: 0.5 }.problem(), Some(String::from("mℓ must be between -l (-1) and +l (1), but is -2")));
+        assert_eq!(QuantumNumbers { n: 1, l: 0, ml: 0, ms: 1.0 }.problem(), Some(String::from("mₛ must be +½ or -½, but is 1")));
     }
 
-    Some(format!("{}", ElectronConfiguration::new(element)))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
     #[test]
-    fn test_electron_configuration() {
-        assert_eq!(get_electron_configuration("H"), Some(String::from("1s1")));
-        assert_eq!(get_electron_configuration("He"), Some(String::from("1s2")));
-        assert_eq!(get_electron_configuration("Li"), Some(String::from("1s2 2s1")));
-        assert_eq!(get_electron_configuration("Be"), Some(String::from("1s2 2s2")));
-        assert_eq!(get_electron_configuration("B"), Some(String::from("1s2 2s2 2p1")));
-        assert_eq!(get_electron_configuration("C"), Some(String::from("1s2 2s2 2p2")));
-        assert_eq!(get_electron_configuration("N"), Some(String::from("1s2 2s2 2p3")));
-        assert_eq!(get_electron_configuration("O"), Some(String::from("1s2 2s2 2p4")));
-        assert_eq!(get_electron_configuration("F"), Some(String::from("1s2 2s2 2p5")));
-        assert_eq!(get_electron_configuration("Ne"), Some(String::from("1s2 2s2 2p6")));
-        assert_eq!(get_electron_configuration("Na"), Some(String::from("1s2 2s2 2p6 3s1")));
-        assert_eq!(get_electron_configuration("Mg"), Some(String::from("1s2 2s2 2p6 3s2")));
-        assert_eq!(get_electron_configuration("Al"), Some(String::from("1s2 2s2 2p6 3s2 3p1")));
-        assert_eq!(get_electron_configuration("Si"), Some(String::from("1s2 2s2 2p6 3s2 3p2")));
-        assert_eq!(get_electron_configuration("P"), Some(String::from("1s2 2s2 2p6 3s2 3p3")));
-        assert_eq!(get_electron_configuration("S"), Some(String::from("1s2 2s2 2p6 3s2 3p4")));
+    fn test_electron_configuration_invalid_element() {
+        assert_eq!(get_electron_configuration("Xx", 0, SubshellOrder::Energy, ConfigurationOutput::Full), None);
     }
 }
@@ -2,25 +2,142 @@ use std::fmt::{Display, Formatter, Result};
 
 use crate::readers::*;
 use crate::constants::*;
+use crate::quantity::*;
+
+// A measured value that can be shown in a choice of units
+pub trait Measurement: Display + Sized {
+    // Sets the units to show the value in (e.g. ["eV", "cm^-1"]), or returns None if any aren't supported
+    // or the value isn't finite in one of them
+    fn with_units(self, units: &[&str]) -> Option<Self>;
+
+    // Checks that the value is finite, since conversions can divide by zero (e.g. the wavelength of a zero frequency)
+    fn is_finite(&self) -> bool;
+}
+
+// Asks which units to show a measurement in, keeping its default units if left blank
+pub fn prompt_units<M: Measurement>(measurement: M) -> String {
+    if !measurement.is_finite() {
+        return String::from("The result is infinite, since a zero value can't be converted.");
+    }
+
+    let units = read_string("Enter the output units, separated by commas (e.g. eV, cm^-1), or leave blank for the defaults: ");
+    if units.is_empty() {
+        return measurement.to_string();
+    }
+
+    let units: Vec<&str> = units.split(',').map(str::trim).collect();
+    match measurement.with_units(&units) {
+        Some(measurement) => measurement.to_string(),
+        None => String::from("Unsupported output unit, or one the result is infinite in."),
+    }
+}
+
+// Checks that every unit is supported, and that the value is finite in each (e.g. 0 m is infinite in cm⁻¹), before keeping them
+fn checked_units<U: ConvertibleUnit>(quantity: Quantity<U>, units: &[&str]) -> Option<Vec<String>> {
+    units.iter()
+        .map(|unit| {
+            let finite = quantity.value_in(unit)?.is_finite() && quantity.uncertainty_in(unit).is_none_or(f64::is_finite);
+            finite.then(|| unit.to_string())
+        })
+        .collect()
+}
+
+// Gets the power of ten of a number written in scientific notation (e.g. "1.6e-21" -> -21)
+pub fn exponent(formatted: &str) -> i32 {
+    formatted.rsplit('e').next().and_then(|exponent| exponent.parse().ok()).unwrap_or(0)
+}
+
+// Formats a value with its uncertainty rounded to 2 significant figures, and the value rounded to the same decimal place
+// e.g. 3.9729e-19 ± 1.589e-21 -> 3.973e-19 ± 1.6e-21
+fn format_uncertain(value: f64, uncertainty: f64) -> String {
+    let uncertainty = format!("{:.1e}", uncertainty);
+
+    // Zero has no power of ten of its own, so it is written with the uncertainty's
+    if value == 0.0 {
+        return format!("0.0e{} ± {}", exponent(&uncertainty), uncertainty);
+    }
+
+    let last_place = exponent(&uncertainty) - 1;
+    let precision = |formatted: &str| (exponent(formatted) - last_place).max(0) as usize;
+
+    let unrounded = format!("{:e}", value);
+    let rounded = format!("{:.1$e}", value, precision(&unrounded));
+
+    // Rounding can carry into the next power of ten (e.g. 9.9996 -> 1.000e1), which needs one more zero
+    let rounded = match rounded.split_once('e') {
+        Some((mantissa, power)) if exponent(&rounded) > exponent(&unrounded) => {
+            format!("{}{}0e{}", mantissa, if mantissa.contains('.') { "" } else { "." }, power)
+        }
+        _ => rounded,
+    };
+
+    format!("{} ± {}", rounded, uncertainty)
+}
+
+// Writes a labelled value, one line per unit, with the later lines lined up under the first
+// Values with an uncertainty are rounded to match it, other values to their significant figures,
+// and exact values are written in full
+fn write_in_units<U: ConvertibleUnit>(f: &mut Formatter<'_>, label: &str, quantity: Quantity<U>, units: &[String]) -> Result {
+    let indent = " ".repeat(label.chars().count() + 2);
+
+    for (index, unit) in units.iter().enumerate() {
+        let value = quantity.value_in(unit).expect("Units should be checked when they are set");
+        let value = match (quantity.uncertainty_in(unit), quantity.significant_figures()) {
+            (Some(uncertainty), _) if uncertainty > 0.0 => format_uncertain(value, uncertainty),
+            (_, Some(significant_figures)) => format!("{:.1$e}", value, significant_figures as usize - 1),
+            (_, None) => format!("{:e}", value),
+        };
+
+        if index == 0 {
+            write!(f, "{}: {} {}", label, value, unit)?;
+        } else {
+            write!(f, "\n{}{} {}", indent, value, unit)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn default_units(units: &[&str]) -> Vec<String> {
+    units.iter().map(|unit| unit.to_string()).collect()
+}
 
-// Frequency value in Hz
 pub struct Frequency {
-    pub value_hz: f64,
-    significant_figures: usize,
+    pub quantity: Quantity<Hertz>,
+    units: Vec<String>,
 }
 
 impl Frequency {
-    pub fn prompt() -> Self {
+    const DEFAULT_UNITS: [&'static str; 1] = ["Hz"];
+
+    pub fn new(quantity: Quantity<Hertz>) -> Self {
         Self {
-            value_hz: read_f64("Enter the frequency (in Hz): "),
-            significant_figures: prompt_sigfigs(),
+            quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
+
+    pub fn prompt() -> Self {
+        Self::new(read_quantity("Enter the frequency (e.g. 4.5e14 Hz, 600 ± 5 THz, 20000 cm^-1; default Hz): ", "Hz"))
+    }
+}
+
+impl Measurement for Frequency {
+    fn with_units(self, units: &[&str]) -> Option<Self> {
+        Some(Self {
+            units: checked_units(self.quantity, units)?,
+            ..self
+        })
+    }
+
+    fn is_finite(&self) -> bool {
+        self.quantity.is_finite()
+    }
 }
 
 impl Display for Frequency {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "Frequency: {:.1$e} Hz", self.value_hz, self.significant_figures)
+        write_in_units(f, "Frequency", self.quantity, &self.units)
     }
 }
 
@@ -32,8 +149,8 @@ impl Display for Frequency {
 impl From<Wavelength> for Frequency {
     fn from(wavelength: Wavelength) -> Self {
         Self {
-            value_hz: SPEED_OF_LIGHT / wavelength.value_m,
-            significant_figures: wavelength.significant_figures,
+            quantity: SPEED_OF_LIGHT / wavelength.quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
@@ -46,8 +163,8 @@ impl From<Wavelength> for Frequency {
 impl From<Energy> for Frequency {
     fn from(energy: Energy) -> Self {
         Self {
-            value_hz: energy.value_j_photon / PLANCK,
-            significant_figures: energy.significant_figures,
+            quantity: energy.quantity / PLANCK,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
@@ -60,26 +177,42 @@ impl From<WorkFunction> for Frequency {
     }
 }
 
-// Wavelength value in m
 pub struct Wavelength {
-    pub value_m: f64,
-    significant_figures: usize,
+    pub quantity: Quantity<Metre>,
+    units: Vec<String>,
 }
 
 impl Wavelength {
-    pub fn prompt() -> Self {
+    const DEFAULT_UNITS: [&'static str; 3] = ["m", "mm", "nm"];
+
+    pub fn new(quantity: Quantity<Metre>) -> Self {
         Self {
-            value_m: read_f64("Enter the wavelength (in m): "),
-            significant_figures: prompt_sigfigs(),
+            quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
+
+    pub fn prompt() -> Self {
+        Self::new(read_quantity("Enter the wavelength (e.g. 500 nm, 500 ± 2 nm, 2.5 µm, 20000 cm^-1; default m): ", "m"))
+    }
+}
+
+impl Measurement for Wavelength {
+    fn with_units(self, units: &[&str]) -> Option<Self> {
+        Some(Self {
+            units: checked_units(self.quantity, units)?,
+            ..self
+        })
+    }
+
+    fn is_finite(&self) -> bool {
+        self.quantity.is_finite()
+    }
 }
 
 impl Display for Wavelength {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "Wavelength: {:.1$e} meters\n", self.value_m, self.significant_figures)?;
-        write!(f, "            {:.1$e} millimeters\n", self.value_m * 1e3, self.significant_figures)?;
-        write!(f, "            {:.1$e} nanometers", self.value_m * 1e9, self.significant_figures)
+        write_in_units(f, "Wavelength", self.quantity, &self.units)
     }
 }
 
@@ -91,8 +224,8 @@ impl Display for Wavelength {
 impl From<Frequency> for Wavelength {
     fn from(frequency: Frequency) -> Self {
         Self {
-            value_m: SPEED_OF_LIGHT / frequency.value_hz,
-            significant_figures: frequency.significant_figures,
+            quantity: SPEED_OF_LIGHT / frequency.quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
@@ -111,8 +244,8 @@ impl From<Energy> for Wavelength {
         // ! CHECK FREQUENCY FROM ENERGY
         // ? What did I write the above comment for?
         Self {
-            value_m: (PLANCK * SPEED_OF_LIGHT) / energy.value_j_photon,
-            significant_figures: energy.significant_figures,
+            quantity: (PLANCK * SPEED_OF_LIGHT) / energy.quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
@@ -125,30 +258,43 @@ impl From<WorkFunction> for Wavelength {
     }
 }
 
+// Energy of a single photon
 pub struct Energy {
-    pub value_j_photon: f64,
-    significant_figures: usize,
+    pub quantity: Quantity<Joule>,
+    units: Vec<String>,
 }
 
 impl Energy {
-    pub fn prompt() -> Self {
+    const DEFAULT_UNITS: [&'static str; 2] = ["J/photon", "kJ/mol"];
+
+    pub fn new(quantity: Quantity<Joule>) -> Self {
         Self {
-            value_j_photon: read_f64("Enter the energy (in J/photon): "),
-            significant_figures: prompt_sigfigs(),
+            quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 
-    // Convert J/photon to kJ/mol
-    // * Does this need to be a method?
-    fn to_kj_mol(&self) -> f64 {
-        self.value_j_photon / 1000.0 * AVOGADRO
+    pub fn prompt() -> Self {
+        Self::new(read_quantity("Enter the energy (e.g. 3.2 eV, 3.2 ± 0.1 eV, 250 kJ/mol; default J/photon): ", "J"))
+    }
+}
+
+impl Measurement for Energy {
+    fn with_units(self, units: &[&str]) -> Option<Self> {
+        Some(Self {
+            units: checked_units(self.quantity, units)?,
+            ..self
+        })
+    }
+
+    fn is_finite(&self) -> bool {
+        self.quantity.is_finite()
     }
 }
 
 impl Display for Energy {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "Energy: {:.1$e} J/photon\n", self.value_j_photon, self.significant_figures)?;
-        write!(f, "        {:.1$e} kJ/mol", self.to_kj_mol(), self.significant_figures)
+        write_in_units(f, "Energy", self.quantity, &self.units)
     }
 }
 
@@ -160,8 +306,8 @@ impl Display for Energy {
 impl From<Frequency> for Energy {
     fn from(frequency: Frequency) -> Self {
         Self {
-            value_j_photon: frequency.value_hz * PLANCK,
-            significant_figures: frequency.significant_figures,
+            quantity: PLANCK * frequency.quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
@@ -182,34 +328,53 @@ impl From<Wavelength> for Energy {
 }
 
 // Energy from Work Function
-// Uses conversion factor for kJ to J, and /mol to /photon
+// Uses Avogadro's number to convert /mol to /photon
 impl From<WorkFunction> for Energy {
     fn from(work_function: WorkFunction) -> Self {
         Self {
-            value_j_photon: (work_function.value_kj_per_mol * 1000.0) / AVOGADRO,
-            significant_figures: work_function.significant_figures,
+            quantity: work_function.quantity / AVOGADRO,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
 
-// Work function in kJ/mol
+// Work function, stored in J/mol and shown in kJ/mol
 pub struct WorkFunction {
-    pub value_kj_per_mol: f64,
-    significant_figures: usize,
+    pub quantity: Quantity<JoulePerMole>,
+    units: Vec<String>,
 }
 
 impl WorkFunction {
-    pub fn prompt() -> Self {
+    const DEFAULT_UNITS: [&'static str; 1] = ["kJ/mol"];
+
+    pub fn new(quantity: Quantity<JoulePerMole>) -> Self {
         Self {
-            value_kj_per_mol: read_f64("Enter the work function (in kJ/mol): "),
-            significant_figures: prompt_sigfigs(),
+            quantity,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
+
+    pub fn prompt() -> Self {
+        Self::new(read_quantity("Enter the work function (e.g. 250 kJ/mol, 250 ± 5 kJ/mol, 2.3 eV; default kJ/mol): ", "kJ/mol"))
+    }
+}
+
+impl Measurement for WorkFunction {
+    fn with_units(self, units: &[&str]) -> Option<Self> {
+        Some(Self {
+            units: checked_units(self.quantity, units)?,
+            ..self
+        })
+    }
+
+    fn is_finite(&self) -> bool {
+        self.quantity.is_finite()
+    }
 }
 
 impl Display for WorkFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "Work function: {:.1$e} kJ/mol", self.value_kj_per_mol, self.significant_figures)
+        write_in_units(f, "Work function", self.quantity, &self.units)
     }
 }
 
@@ -218,11 +383,101 @@ impl Display for WorkFunction {
     // E is the energy (J)
     // h is Planck's constant (J*s)
     // ν is the frequency (Hz)
+// Then uses Avogadro's number to convert /photon to /mol
 impl From<Frequency> for WorkFunction {
     fn from(frequency: Frequency) -> Self {
         Self {
-            value_kj_per_mol: frequency.value_hz * PLANCK,
-            significant_figures: frequency.significant_figures,
+            quantity: PLANCK * frequency.quantity * AVOGADRO,
+            units: default_units(&Self::DEFAULT_UNITS),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        let frequency = || Frequency::new(Quantity::measured(1.0e15, 3));
+
+        assert!((Wavelength::from(frequency()).quantity.value() - 2.99792458e-7).abs() < 1e-15);
+        assert!((Energy::from(frequency()).quantity.value() - 6.62607015e-19).abs() < 1e-27);
+
+        // The threshold work function is per mole of photons, not per photon
+        let work_function = WorkFunction::from(frequency());
+        assert!((work_function.quantity.value() / 1000.0 - 399.0).abs() < 0.1);
+        assert!((Frequency::from(work_function).quantity.value() - 1.0e15).abs() < 1e6);
+    }
+
+    #[test]
+    fn test_display() {
+        let energy = Energy::new(Quantity::measured(6.626e-19, 3));
+
+        assert_eq!(energy.to_string(), "Energy: 6.63e-19 J/photon\n        3.99e2 kJ/mol");
+        assert_eq!(Frequency::from(energy).to_string(), "Frequency: 1.00e15 Hz");
+    }
+
+    #[test]
+    fn test_significant_figures() {
+        // The wavelength limits the result to 1 significant figure, then 3 once it is typed more precisely
+        let energy = Energy::from(Wavelength::new(parse_quantity("500 nm", "m").unwrap()));
+        assert_eq!(energy.to_string(), "Energy: 4e-19 J/photon\n        2e2 kJ/mol");
+
+        let energy = Energy::from(Wavelength::new(parse_quantity("500. nm", "m").unwrap()));
+        assert_eq!(energy.to_string(), "Energy: 3.97e-19 J/photon\n        2.39e2 kJ/mol");
+
+        // The constants are exact, so they never limit the precision
+        let frequency = Frequency::from(Energy::new(parse_quantity("3.973000e-19", "J").unwrap()));
+        assert_eq!(frequency.quantity.significant_figures(), Some(7));
+    }
+
+    #[test]
+    fn test_output_units() {
+        let wavelength = Wavelength::new(Quantity::measured(5.00e-7, 3));
+        assert_eq!(wavelength.with_units(&["nm", "µm", "cm⁻¹"]).unwrap().to_string(), "Wavelength: 5.00e2 nm\n            5.00e-1 µm\n            2.00e4 cm⁻¹");
+
+        let energy = Energy::new(Quantity::measured(3.20, 3) * ELECTRON_VOLT.value());
+        assert_eq!(energy.with_units(&["eV", "kcal/mol"]).unwrap().to_string(), "Energy: 3.20e0 eV\n        7.38e1 kcal/mol");
+
+        let frequency = Frequency::new(Quantity::measured(6.00e14, 3));
+        assert_eq!(frequency.with_units(&["THz"]).unwrap().to_string(), "Frequency: 6.00e2 THz");
+
+        assert!(WorkFunction::new(Quantity::measured(250_000.0, 3)).with_units(&["eV", "nm"]).is_none());
+        assert!(Wavelength::new(Quantity::measured(0.0, 1)).with_units(&["nm", "cm^-1"]).is_none());
+    }
+
+    #[test]
+    fn test_infinite_results() {
+        assert!(!Wavelength::from(Frequency::new(Quantity::measured(0.0, 1))).is_finite());
+        assert!(!Frequency::from(Wavelength::new(Quantity::measured(0.0, 1))).is_finite());
+        assert!(Energy::from(Frequency::new(Quantity::measured(0.0, 1))).is_finite());
+    }
+
+    #[test]
+    fn test_uncertainty() {
+        // E = hc/λ with h and c exact, so the energy has the same relative uncertainty as the wavelength
+        let energy = Energy::from(Wavelength::new(parse_quantity("500. ± 2 nm", "m").unwrap()));
+        let expected = energy.quantity.value() * 2.0 / 500.0;
+        assert!((energy.quantity.uncertainty().unwrap() - expected).abs() < expected * 1e-9);
+        assert_eq!(energy.to_string(), "Energy: 3.973e-19 ± 1.6e-21 J/photon\n        2.3925e2 ± 9.6e-1 kJ/mol");
+
+        // The uncertainty decides how many digits are shown, even past the significant figures
+        let energy = Energy::from(Wavelength::new(parse_quantity("500 ± 20 nm", "m").unwrap()));
+        assert_eq!(energy.to_string(), "Energy: 3.97e-19 ± 1.6e-20 J/photon\n        2.393e2 ± 9.6e0 kJ/mol");
+        assert_eq!(format_uncertain(9.9996, 0.012), "1.0000e1 ± 1.2e-2");
+        assert_eq!(format_uncertain(0.0, 1.589e-21), "0.0e-21 ± 1.6e-21");
+
+        // Converting through N_A gives the same uncertainty as showing the energy in kJ/mol
+        let work_function = WorkFunction::new(parse_quantity("250. ± 5 kJ/mol", "kJ/mol").unwrap());
+        let energy = Energy::from(WorkFunction::new(work_function.quantity));
+        assert!((energy.quantity.uncertainty_in("kJ/mol").unwrap() - 5.0).abs() < 1e-9);
+        assert!((work_function.quantity.uncertainty_in("eV").unwrap() - energy.quantity.uncertainty_in("eV").unwrap()).abs() < 1e-12);
+
+        // The Rydberg energy is the only constant with an uncertainty
+        assert_eq!(Frequency::from(Energy::new(Quantity::new(6.626e-19))).quantity.uncertainty(), None);
+        assert!(RYDBERG.uncertainty().is_some());
+
+        assert_eq!(Energy::new(Quantity::measured(1.0e-19, 2)).quantity.uncertainty(), None);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::constants::*;
+use crate::readers::*;
+
+// ANSI escape codes for coloring terminal text
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+const HIGHLIGHTED: &str = "\x1b[1;30;43m";
+const NO_DATA: &str = "\x1b[90m";
+
+// 256-color backgrounds running from blue (lowest) to red (highest)
+const HEATMAP: [u8; 11] = [21, 27, 33, 39, 45, 49, 118, 190, 220, 208, 196];
+
+// Gets a numeric property of an element, if it is known
+pub type ElementValue = Box<dyn Fn(&Element) -> Option<f64>>;
+
+// What to draw attention to when rendering the periodic table
+pub enum Highlight {
+    // Colors every element by its block
+    None,
+    Element(u32),
+    Block(Block),
+    Group(u32),
+    // Colors every element by a property, labelled for the legend
+    // Elements without a value for the property are grayed out
+    Heatmap(String, ElementValue),
+}
+
+impl Highlight {
+    // Returns None if the element, block or group doesn't exist
+    pub fn prompt() -> Option<Self> {
+        match read_i32("Enter 1 for no highlight, 2 for an element, 3 for a block, 4 for a group or 5 for a heatmap: ") {
+            2 => Element::lookup(&read_string("Enter the element symbol, name or atomic number: "))
+                .map(|element| Highlight::Element(element.atomic_number)),
+            3 => match read_string("Enter the block (s, p, d or f): ").to_lowercase().as_str() {
+                "s" => Some(Highlight::Block(Block::S)),
+                "p" => Some(Highlight::Block(Block::P)),
+                "d" => Some(Highlight::Block(Block::D)),
+                "f" => Some(Highlight::Block(Block::F)),
+                _ => None,
+            },
+            4 => match read_i32("Enter the group (1-18): ") {
+                group @ 1..=18 => Some(Highlight::Group(group as u32)),
+                _ => None,
+            },
+            5 => {
+                println!("1. Atomic weight (g/mol)");
+                for (index, property) in Property::ALL.iter().enumerate() {
+                    match property.coverage() {
+                        Some(coverage) => println!("{}. {} {} ({})", index + 2, property, property.unit(), coverage),
+                        None => println!("{}. {} {}", index + 2, property, property.unit()),
+                    }
+                }
+
+                match read_i32("Enter the property: ") {
+                    1 => Some(Highlight::Heatmap(String::from("atomic weight (g/mol)"), Box::new(|element| Some(element.atomic_weight)))),
+                    choice => Property::ALL.get(usize::try_from(choice - 2).ok()?).map(|&property| {
+                        let label = format!("{} {}", property.to_string().to_lowercase(), property.unit());
+                        Highlight::Heatmap(label, Box::new(move |element: &Element| property.value(element)))
+                    }),
+                }
+            }
+            _ => Some(Highlight::None),
+        }
+    }
+}
+
+// Gets the row and column an element is drawn at, both starting from 1
+// Rows 1-7 are the periods, and rows 8 and 9 hold the f-block split out below the main table
+fn position(element: &Element) -> (u32, u32) {
+    match element.group {
+        Some(group) => (element.period, group),
+        None => {
+            // Lanthanides start at La (57) and actinides at Ac (89), both drawn from column 3
+            let first = if element.period == 6 { 57 } else { 89 };
+            (element.period + 2, element.atomic_number - first + 3)
+        }
+    }
+}
+
+// Gets the foreground color of a block when nothing is highlighted
+fn block_color(block: Block) -> &'static str {
+    match block {
+        Block::S => "\x1b[31m",
+        Block::P => "\x1b[33m",
+        Block::D => "\x1b[34m",
+        Block::F => "\x1b[32m",
+    }
+}
+
+// Renders the 18-column periodic table as colored terminal text
+pub fn periodic_table(highlight: &Highlight) -> String {
+    // The lowest and highest heatmap values, to scale the colors between
+    let range = match highlight {
+        Highlight::Heatmap(_, property) => ELEMENTS.iter()
+            .filter_map(property)
+            .fold(None, |range: Option<(f64, f64)>, value| match range {
+                Some((low, high)) => Some((low.min(value), high.max(value))),
+                None => Some((value, value)),
+            }),
+        _ => None,
+    };
+
+    let style = |element: &Element| -> String {
+        match highlight {
+            Highlight::None => String::from(block_color(element.block)),
+            Highlight::Element(atomic_number) => String::from(if element.atomic_number == *atomic_number { HIGHLIGHTED } else { DIM }),
+            Highlight::Block(block) => String::from(if element.block == *block { HIGHLIGHTED } else { DIM }),
+            Highlight::Group(group) => String::from(if element.group == Some(*group) { HIGHLIGHTED } else { DIM }),
+            Highlight::Heatmap(_, property) => match (property(element), range) {
+                (Some(value), Some((low, high))) => {
+                    let scale = if high > low { (value - low) / (high - low) } else { 0.0 };
+                    let color = HEATMAP[(scale * (HEATMAP.len() - 1) as f64).round() as usize];
+                    format!("\x1b[1;30;48;5;{}m", color)
+                }
+                _ => String::from(NO_DATA),
+            },
+        }
+    };
+
+    let mut grid = [[None; 18]; 9];
+    for element in ELEMENTS.iter() {
+        let (row, column) = position(element);
+        grid[row as usize - 1][column as usize - 1] = Some(element);
+    }
+
+    let mut lines = vec![format!("   {}", (1..=18).map(|group| format!("{:<3}", group)).collect::<String>().trim_end())];
+
+    for (index, row) in grid.iter().enumerate() {
+        // Leave a gap between the main table and the f-block
+        if index == 7 {
+            lines.push(String::new());
+        }
+
+        let label = if index < 7 { (index + 1).to_string() } else { String::new() };
+        let cells: String = row.iter()
+            .map(|cell| match cell {
+                Some(element) => format!("{}{:<2}{} ", style(element), element.symbol, RESET),
+                None => String::from("   "),
+            })
+            .collect();
+
+        lines.push(format!("{:<3}{}", label, cells).trim_end().to_string());
+    }
+
+    lines.push(String::new());
+    lines.push(match (highlight, range) {
+        (Highlight::None, _) => format!(
+            "Colored by block: {}s{} {}p{} {}d{} {}f{}",
+            block_color(Block::S), RESET, block_color(Block::P), RESET, block_color(Block::D), RESET, block_color(Block::F), RESET,
+        ),
+        (Highlight::Heatmap(label, _), Some((low, high))) => format!(
+            "Heatmap of {}: {} (blue) to {} (red), gray has no data",
+            label, low, high,
+        ),
+        (Highlight::Heatmap(label, _), None) => format!("No data for {}", label),
+        _ => String::from("Highlighted elements are shown in yellow"),
+    });
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Removes the ANSI escape codes, leaving the plain text layout
+    fn plain(text: &str) -> String {
+        let mut plain = String::new();
+        let mut escaped = false;
+
+        for c in text.chars() {
+            match c {
+                '\x1b' => escaped = true,
+                'm' if escaped => escaped = false,
+                _ if !escaped => plain.push(c),
+                _ => {}
+            }
+        }
+
+        plain
+    }
+
+    #[test]
+    fn test_layout() {
+        let table = plain(&periodic_table(&Highlight::None));
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[1], format!("1  H{}He", " ".repeat(50)));
+        assert!(lines[6].starts_with("6  Cs Ba Lu Hf"), "{}", lines[6]);
+        assert!(lines[9].starts_with("         La Ce Pr"), "{}", lines[9]);
+        assert!(lines[10].trim_end().ends_with("Md No"), "{}", lines[10]);
+
+        for element in ELEMENTS.iter() {
+            assert!(table.contains(element.symbol), "{}", element.symbol);
+        }
+    }
+
+    #[test]
+    fn test_positions() {
+        let cases = [("H", (1, 1)), ("Fe", (4, 8)), ("Lu", (6, 3)), ("La", (8, 3)), ("Yb", (8, 16)), ("Ac", (9, 3)), ("No", (9, 16)), ("Og", (7, 18))];
+
+        for (symbol, expected) in cases {
+            assert_eq!(position(Element::from_symbol(symbol).unwrap()), expected, "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn test_highlight() {
+        let table = periodic_table(&Highlight::Element(26));
+        assert!(table.contains(&format!("{}Fe", HIGHLIGHTED)));
+        assert_eq!(table.matches(HIGHLIGHTED).count(), 1);
+
+        let table = periodic_table(&Highlight::Group(18));
+        assert_eq!(table.matches(HIGHLIGHTED).count(), 7);
+
+        let table = periodic_table(&Highlight::Block(Block::F));
+        assert_eq!(table.matches(HIGHLIGHTED).count(), 28);
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let table = periodic_table(&Highlight::Heatmap(String::from("atomic weight"), Box::new(|element| Some(element.atomic_weight))));
+
+        assert!(table.contains(&format!("\x1b[1;30;48;5;{}mH ", HEATMAP[0])));
+        assert!(table.contains(&format!("\x1b[1;30;48;5;{}mOg", HEATMAP[HEATMAP.len() - 1])));
+        assert!(table.ends_with("Heatmap of atomic weight: 1.008 (blue) to 294 (red), gray has no data"));
+    }
+}
@@ -0,0 +1,568 @@
+use std::fmt::{Debug, Display, Formatter, LowerExp, Result};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::constants::*;
+
+// A unit of measurement, used only as a type parameter
+pub trait Unit {
+    const SYMBOL: &'static str;
+}
+
+// Gives the unit of multiplying a quantity in this unit by one in Rhs
+// Only products that make physical sense are implemented, so anything else fails to compile
+pub trait UnitMul<Rhs> {
+    type Output;
+}
+
+// Gives the unit of dividing a quantity in this unit by one in Rhs
+pub trait UnitDiv<Rhs> {
+    type Output;
+}
+
+// Declares a unit marker type with its symbol
+macro_rules! unit {
+    ($name:ident, $symbol:expr) => {
+        pub enum $name {}
+
+        impl Unit for $name {
+            const SYMBOL: &'static str = $symbol;
+        }
+    };
+}
+
+// Declares that A × B = C, along with B × A = C, C / A = B and C / B = A
+macro_rules! product {
+    ($a:ty, $b:ty, $c:ty) => {
+        impl UnitMul<$b> for $a {
+            type Output = $c;
+        }
+
+        impl UnitMul<$a> for $b {
+            type Output = $c;
+        }
+
+        impl UnitDiv<$a> for $c {
+            type Output = $b;
+        }
+
+        impl UnitDiv<$b> for $c {
+            type Output = $a;
+        }
+    };
+}
+
+unit!(Hertz, "Hz");
+unit!(Metre, "m");
+unit!(Joule, "J");
+unit!(JouleSecond, "J*s");
+unit!(JouleMetre, "J*m");
+unit!(MetrePerSecond, "m/s");
+unit!(PerMole, "/mol");
+unit!(JoulePerMole, "J/mol");
+unit!(Gram, "g");
+unit!(Mole, "mol");
+unit!(GramPerMole, "g/mol");
+unit!(Dimensionless, "");
+
+// c = λν
+product!(Metre, Hertz, MetrePerSecond);
+// E = hν
+product!(JouleSecond, Hertz, Joule);
+// hc, so that E = hc/λ
+product!(JouleSecond, MetrePerSecond, JouleMetre);
+product!(Joule, Metre, JouleMetre);
+// Per photon to per mole
+product!(Joule, PerMole, JoulePerMole);
+
+// m = nM
+product!(Mole, GramPerMole, Gram);
+// N = nN_A
+product!(Mole, PerMole, Dimensionless);
+
+// Mass fractions, e.g. of an element's share of a molar mass, and ratios of masses such as percent yield
+impl UnitDiv<GramPerMole> for GramPerMole {
+    type Output = Dimensionless;
+}
+
+impl UnitDiv<Gram> for Gram {
+    type Output = Dimensionless;
+}
+
+// A plain number times a unit is a quantity in that unit, e.g. for a number typed before its unit was chosen
+impl<U: Unit> UnitMul<U> for Dimensionless {
+    type Output = U;
+}
+
+// Metric prefixes and the factors they multiply by
+// Both "µ" and "u" are accepted for micro, since "µ" is hard to type
+const PREFIXES: [(&str, f64); 21] = [
+    ("Y", 1e24), ("Z", 1e21), ("E", 1e18), ("P", 1e15), ("T", 1e12), ("G", 1e9), ("M", 1e6), ("k", 1e3), ("h", 1e2), ("da", 1e1),
+    ("d", 1e-1), ("c", 1e-2), ("m", 1e-3), ("µ", 1e-6), ("u", 1e-6), ("n", 1e-9), ("p", 1e-12), ("f", 1e-15), ("a", 1e-18), ("z", 1e-21), ("y", 1e-24),
+];
+
+// Scales a value by the metric prefix on a unit, if the rest of the unit is the base (e.g. "nm" with base "m")
+fn scale_prefixed(value: f64, unit: &str, base: &str) -> Option<f64> {
+    if unit == base {
+        return Some(value);
+    }
+
+    let prefix = unit.strip_suffix(base)?;
+    PREFIXES.iter().find(|(symbol, _)| *symbol == prefix).map(|(_, factor)| value * factor)
+}
+
+// Gets a wavenumber in cm⁻¹ as a photon energy
+// Uses the formula E = hcν̃, where
+    // E is the energy (J)
+    // h is Planck's constant (J*s)
+    // c is the speed of light (m/s)
+    // ν̃ is the wavenumber (converted from cm⁻¹ to m⁻¹)
+fn wavenumber_energy(wavenumber: f64) -> f64 {
+    (PLANCK * SPEED_OF_LIGHT).value() * wavenumber * 100.0
+}
+
+// Rewrites the different ways of typing a unit into one form (e.g. "cm⁻¹" and "cm-1" -> "cm^-1", "μm" -> "µm")
+pub fn normalize_unit(unit: &str) -> String {
+    let unit = unit.replace(' ', "")
+        .replace('⁻', "^-")
+        .replace('¹', "1")
+        // Greek mu and the Ångström sign look the same as, but differ from, the micro sign and Å
+        .replace('μ', "µ")
+        .replace('\u{212B}', "Å")
+        .replace("/photon", "");
+
+    match unit.strip_suffix("-1") {
+        Some(base) if !base.ends_with('^') => format!("{}^-1", base),
+        _ => unit.replace("1/s", "/s"),
+    }
+}
+
+// A unit that values can be converted to and from other units in, e.g. for input and output
+pub trait ConvertibleUnit: Unit + Sized {
+    // Converts a value in the given unit to this unit, or returns None if the unit isn't supported
+    // Units are normalized first, so e.g. "cm⁻¹" arrives as "cm^-1" and "μm" as "µm"
+    fn convert(value: f64, unit: &str) -> Option<f64>;
+
+    // Converts a value in this unit to the given unit
+    // Works for any unit that is a multiple of this one, so only reciprocal units need to override it
+    fn convert_to(value: f64, unit: &str) -> Option<f64> {
+        Self::convert(1.0, unit).map(|factor| value / factor)
+    }
+}
+
+impl ConvertibleUnit for Metre {
+    fn convert(value: f64, unit: &str) -> Option<f64> {
+        match unit {
+            "Å" | "angstrom" => Some(value * 1e-10),
+            // A wavenumber is the reciprocal of the wavelength
+            "cm^-1" => Some(1.0 / (value * 100.0)),
+            _ => scale_prefixed(value, unit, "m"),
+        }
+    }
+
+    fn convert_to(value: f64, unit: &str) -> Option<f64> {
+        match unit {
+            "cm^-1" => Some(1.0 / (value * 100.0)),
+            _ => Self::convert(1.0, unit).map(|factor| value / factor),
+        }
+    }
+}
+
+impl ConvertibleUnit for Hertz {
+    fn convert(value: f64, unit: &str) -> Option<f64> {
+        match unit {
+            "s^-1" | "/s" => Some(value),
+            // ν = cν̃
+            "cm^-1" => Some(SPEED_OF_LIGHT.value() * value * 100.0),
+            _ => scale_prefixed(value, unit, "Hz"),
+        }
+    }
+}
+
+impl ConvertibleUnit for Joule {
+    fn convert(value: f64, unit: &str) -> Option<f64> {
+        match unit {
+            "cm^-1" => Some(wavenumber_energy(value)),
+            // Molar energies are converted to the energy of a single photon
+            _ => scale_prefixed(value, unit, "J")
+                .or_else(|| scale_prefixed(value, unit, "eV").map(|value| value * ELECTRON_VOLT.value()))
+                .or_else(|| scale_prefixed(value, unit, "J/mol").map(|value| value / AVOGADRO.value()))
+                .or_else(|| scale_prefixed(value, unit, "cal/mol").map(|value| value * CALORIE.value() / AVOGADRO.value())),
+        }
+    }
+}
+
+impl ConvertibleUnit for JoulePerMole {
+    fn convert(value: f64, unit: &str) -> Option<f64> {
+        match unit {
+            "cm^-1" => Some(wavenumber_energy(value) * AVOGADRO.value()),
+            // Energies of a single photon are converted to a mole of photons
+            _ => scale_prefixed(value, unit, "J/mol")
+                .or_else(|| scale_prefixed(value, unit, "cal/mol").map(|value| value * CALORIE.value()))
+                .or_else(|| scale_prefixed(value, unit, "eV").map(|value| value * ELECTRON_VOLT.value() * AVOGADRO.value()))
+                .or_else(|| scale_prefixed(value, unit, "J").map(|value| value * AVOGADRO.value())),
+        }
+    }
+}
+
+// Gets the power of ten of a value's leading digit (e.g. 2 for 345, -3 for 0.00123)
+fn magnitude(value: f64) -> i32 {
+    if value == 0.0 {
+        0
+    } else {
+        value.abs().log10().floor() as i32
+    }
+}
+
+// Gets the power of ten of a value's last significant digit (e.g. 1 for 345 with 2 significant figures)
+fn last_significant_place(value: f64, significant_figures: u32) -> i32 {
+    magnitude(value) - significant_figures as i32 + 1
+}
+
+// Combines independent uncertainties to first order, using σ = √(σ₁² + σ₂²)
+// Returns None only if neither has an uncertainty
+fn combine_uncertainties(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or(0.0).hypot(b.unwrap_or(0.0))),
+    }
+}
+
+// A value in SI units, tagged with its unit at compile time
+// Measured values carry their significant figures, while exact values (e.g. unit conversions) have None
+// Values may also carry a ± uncertainty (in the same SI unit), which is propagated to first order
+pub struct Quantity<U> {
+    value: f64,
+    significant_figures: Option<u32>,
+    uncertainty: Option<f64>,
+    unit: PhantomData<U>,
+}
+
+impl<U> Quantity<U> {
+    pub const fn new(value: f64) -> Self {
+        Self { value, significant_figures: None, uncertainty: None, unit: PhantomData }
+    }
+
+    pub const fn measured(value: f64, significant_figures: u32) -> Self {
+        Self { value, significant_figures: Some(significant_figures), uncertainty: None, unit: PhantomData }
+    }
+
+    pub const fn with_uncertainty(self, uncertainty: f64) -> Self {
+        Self { uncertainty: Some(uncertainty), ..self }
+    }
+
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    pub fn significant_figures(self) -> Option<u32> {
+        self.significant_figures
+    }
+
+    pub fn uncertainty(self) -> Option<f64> {
+        self.uncertainty
+    }
+
+    // Checks that the value and any uncertainty are finite, which they stop being after e.g. dividing by zero
+    pub fn is_finite(self) -> bool {
+        self.value.is_finite() && self.uncertainty.is_none_or(f64::is_finite)
+    }
+
+    // Gets the significant figures of a product or quotient, which is limited by the least precise factor
+    fn product_figures(self, other: Quantity<impl Sized>) -> Option<u32> {
+        match (self.significant_figures, other.significant_figures) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (figures, None) | (None, figures) => figures,
+        }
+    }
+
+    // Gets the significant figures of a sum or difference, which is limited by the least precise decimal place
+    fn sum_figures(self, other: Self, result: f64) -> Option<u32> {
+        let place = |quantity: Self| quantity.significant_figures.map(|figures| last_significant_place(quantity.value, figures));
+        let place = match (place(self), place(other)) {
+            (Some(a), Some(b)) => a.max(b),
+            (place, None) | (None, place) => place?,
+        };
+
+        // Cancellation can leave no significant digits, but the result still has at least one
+        Some((magnitude(result) - place + 1).max(1) as u32)
+    }
+
+    fn derived(value: f64, significant_figures: Option<u32>, uncertainty: Option<f64>) -> Self {
+        Self { value, significant_figures, uncertainty, unit: PhantomData }
+    }
+
+    // Scales by an exact factor, which scales the uncertainty too
+    fn scaled(self, factor: f64) -> Self {
+        Self::derived(self.value * factor, self.significant_figures, self.uncertainty.map(|uncertainty| uncertainty * factor.abs()))
+    }
+}
+
+impl<U: ConvertibleUnit> Quantity<U> {
+    // Gets the value in another unit (e.g. "nm", "eV", "cm⁻¹"), or None if the unit isn't supported
+    pub fn value_in(self, unit: &str) -> Option<f64> {
+        U::convert_to(self.value, &normalize_unit(unit))
+    }
+
+    // Gets the uncertainty in another unit, keeping the same relative uncertainty as the value
+    // This is first order for reciprocal units like cm⁻¹, and exact for the rest
+    pub fn uncertainty_in(self, unit: &str) -> Option<f64> {
+        let uncertainty = self.uncertainty?;
+
+        if self.value == 0.0 {
+            U::convert_to(uncertainty, &normalize_unit(unit)).map(f64::abs)
+        } else {
+            Some((self.value_in(unit)? * uncertainty / self.value).abs())
+        }
+    }
+}
+
+// Implemented by hand, since deriving would require the unit itself to be Clone, Copy, etc.
+impl<U> Clone for Quantity<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U> Copy for Quantity<U> {}
+
+impl<U> PartialEq for Quantity<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<U: Unit> Debug for Quantity<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?} {}", self.value, U::SYMBOL)
+    }
+}
+
+impl<U: Unit> Display for Quantity<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.value, f)?;
+        write!(f, " {}", U::SYMBOL)
+    }
+}
+
+// Passes the precision through, so "{:.2e}" gives e.g. "6.63e-34 J*s"
+impl<U: Unit> LowerExp for Quantity<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerExp::fmt(&self.value, f)?;
+        write!(f, " {}", U::SYMBOL)
+    }
+}
+
+impl<U> Add for Quantity<U> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let value = self.value + other.value;
+        Self::derived(value, self.sum_figures(other, value), combine_uncertainties(self.uncertainty, other.uncertainty))
+    }
+}
+
+impl<U> Sub for Quantity<U> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let value = self.value - other.value;
+        Self::derived(value, self.sum_figures(other, value), combine_uncertainties(self.uncertainty, other.uncertainty))
+    }
+}
+
+impl<U> Neg for Quantity<U> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.scaled(-1.0)
+    }
+}
+
+// Uses σ = √((bσₐ)² + (aσᵦ)²) for the uncertainty of ab
+impl<A: UnitMul<B>, B> Mul<Quantity<B>> for Quantity<A> {
+    type Output = Quantity<A::Output>;
+
+    fn mul(self, other: Quantity<B>) -> Self::Output {
+        let uncertainty = combine_uncertainties(
+            self.uncertainty.map(|uncertainty| uncertainty * other.value.abs()),
+            other.uncertainty.map(|uncertainty| uncertainty * self.value.abs()),
+        );
+
+        Quantity::derived(self.value * other.value, self.product_figures(other), uncertainty)
+    }
+}
+
+// Uses σ = √((σₐ/b)² + (aσᵦ/b²)²) for the uncertainty of a/b
+fn quotient_uncertainty<A, B>(a: Quantity<A>, b: Quantity<B>) -> Option<f64> {
+    combine_uncertainties(
+        a.uncertainty.map(|uncertainty| uncertainty / b.value.abs()),
+        b.uncertainty.map(|uncertainty| uncertainty * (a.value / (b.value * b.value)).abs()),
+    )
+}
+
+impl<A: UnitDiv<B>, B> Div<Quantity<B>> for Quantity<A> {
+    type Output = Quantity<A::Output>;
+
+    fn div(self, other: Quantity<B>) -> Self::Output {
+        Quantity::derived(self.value / other.value, self.product_figures(other), quotient_uncertainty(self, other))
+    }
+}
+
+// Scaling by a plain number keeps the unit, and treats the number as exact
+impl<U> Mul<f64> for Quantity<U> {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        self.scaled(factor)
+    }
+}
+
+impl<U> Mul<Quantity<U>> for f64 {
+    type Output = Quantity<U>;
+
+    fn mul(self, quantity: Quantity<U>) -> Quantity<U> {
+        quantity * self
+    }
+}
+
+impl<U> Div<f64> for Quantity<U> {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        self.scaled(1.0 / divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_photon_energy() {
+        let frequency: Quantity<Hertz> = Quantity::new(5.0e14);
+        let energy: Quantity<Joule> = PLANCK * frequency;
+
+        assert!((energy.value() - 3.313e-19).abs() < 1e-22);
+        assert!(((energy / PLANCK).value() - 5.0e14).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_wavelength_and_frequency() {
+        let wavelength: Quantity<Metre> = Quantity::new(500e-9);
+        let frequency: Quantity<Hertz> = SPEED_OF_LIGHT / wavelength;
+        let energy: Quantity<Joule> = PLANCK * SPEED_OF_LIGHT / wavelength;
+
+        assert!((frequency.value() - 5.996e14).abs() < 1e11);
+        assert!(((SPEED_OF_LIGHT / frequency).value() - 500e-9).abs() < 1e-15);
+        assert!((energy.value() - 3.973e-19).abs() < 1e-22);
+    }
+
+    #[test]
+    fn test_scalar_arithmetic() {
+        let energy: Quantity<Joule> = Quantity::new(2.0);
+
+        assert_eq!(energy + energy, Quantity::new(4.0));
+        assert_eq!(energy - energy * 0.5, Quantity::new(1.0));
+        assert_eq!(3.0 * energy / 2.0, Quantity::new(3.0));
+        assert_eq!(-energy, Quantity::new(-2.0));
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(format!("{:.2e}", PLANCK), "6.63e-34 J*s");
+        assert_eq!(format!("{}", Quantity::<Metre>::new(1.5)), "1.5 m");
+    }
+
+    #[test]
+    fn test_unit_conversion() {
+        let close = |actual: Option<f64>, expected: f64| (actual.unwrap() - expected).abs() <= expected.abs() * 1e-6;
+
+        assert!(close(Metre::convert(500.0, "nm"), 5.0e-7));
+        assert!(close(Metre::convert(2.5, "µm"), 2.5e-6));
+        assert!(close(Metre::convert(1.0, "Å"), 1.0e-10));
+        assert!(close(Metre::convert(20000.0, "cm^-1"), 5.0e-7));
+        assert!(close(Hertz::convert(4.5e14, "s^-1"), 4.5e14));
+        assert!(close(Hertz::convert(600.0, "THz"), 6.0e14));
+        assert!(close(Joule::convert(3.2, "eV"), 5.126965e-19));
+        assert!(close(Joule::convert(1.0, "kJ/mol"), 1.0e3 / 6.02214076e23));
+        assert!(close(JoulePerMole::convert(1.0, "kcal/mol"), 4184.0));
+        assert!(close(JoulePerMole::convert(1.0, "eV"), 96_485.33));
+
+        assert_eq!(Metre::convert(1.0, "Hz"), None);
+        assert_eq!(Metre::convert(1.0, "xm"), None);
+        assert_eq!(Joule::convert(1.0, "kg"), None);
+    }
+
+    #[test]
+    fn test_value_in() {
+        let close = |actual: Option<f64>, expected: f64| (actual.unwrap() - expected).abs() <= expected.abs() * 1e-6;
+        let wavelength: Quantity<Metre> = Quantity::new(5.0e-7);
+        let energy: Quantity<Joule> = Quantity::new(3.2 * ELECTRON_VOLT.value());
+
+        assert!(close(wavelength.value_in("nm"), 500.0));
+        assert!(close(wavelength.value_in("Å"), 5000.0));
+        assert!(close(wavelength.value_in("µm"), 0.5));
+        assert!(close(wavelength.value_in("cm⁻¹"), 20000.0));
+        assert!(close(energy.value_in("eV"), 3.2));
+        assert!(close(energy.value_in("J/photon"), energy.value()));
+        assert!(close(Quantity::<Hertz>::new(6.0e14).value_in("THz"), 600.0));
+        assert!(close(Quantity::<JoulePerMole>::new(4184.0).value_in("kcal/mol"), 1.0));
+        assert_eq!(wavelength.value_in("eV"), None);
+    }
+
+    #[test]
+    fn test_product_significant_figures() {
+        let frequency: Quantity<Hertz> = Quantity::measured(3.20e14, 3);
+
+        // PLANCK has 4 significant figures, so the frequency limits the product
+        assert_eq!((PLANCK * frequency).significant_figures(), Some(3));
+        assert_eq!((SPEED_OF_LIGHT / Quantity::<Metre>::measured(5e-7, 1)).significant_figures(), Some(1));
+        assert_eq!((frequency * 2.0).significant_figures(), Some(3));
+        assert_eq!((Quantity::<JouleSecond>::new(1.0) * Quantity::<Hertz>::new(2.0)).significant_figures(), None);
+    }
+
+    #[test]
+    fn test_sum_significant_figures() {
+        let sum = Quantity::<Joule>::measured(12.11, 4) + Quantity::measured(18.0, 3) + Quantity::measured(1.013, 4);
+        assert_eq!(sum.significant_figures(), Some(3));
+
+        // 100.0 - 99.5 is only known to the tenths place
+        let difference = Quantity::<Joule>::measured(100.0, 4) - Quantity::measured(99.5, 3);
+        assert_eq!(difference.significant_figures(), Some(1));
+
+        let exact = Quantity::<Joule>::measured(1.5, 2) + Quantity::new(100.0);
+        assert_eq!(exact.significant_figures(), Some(4));
+    }
+
+    #[test]
+    fn test_uncertainty_propagation() {
+        let close = |actual: Option<f64>, expected: f64| (actual.unwrap() - expected).abs() <= expected.abs() * 1e-9;
+        let a: Quantity<Joule> = Quantity::new(10.0).with_uncertainty(0.3);
+        let b: Quantity<Joule> = Quantity::new(4.0).with_uncertainty(0.4);
+
+        assert!(close((a + b).uncertainty(), 0.5));
+        assert!(close((a - b).uncertainty(), 0.5));
+        assert!(close((a * 2.0).uncertainty(), 0.6));
+        assert!(close((-a).uncertainty(), 0.3));
+
+        // Relative uncertainties of 3% and 4% give 5% for a product or quotient
+        let frequency: Quantity<Hertz> = Quantity::new(4.0).with_uncertainty(0.16);
+        let action: Quantity<JouleSecond> = Quantity::new(10.0).with_uncertainty(0.3);
+        assert!(close((action * frequency).uncertainty(), 2.0));
+        assert!(close((a / action).uncertainty(), 0.03f64.hypot(0.03)));
+
+        assert_eq!((Quantity::<Joule>::new(1.0) + Quantity::new(2.0)).uncertainty(), None);
+    }
+
+    #[test]
+    fn test_uncertainty_in() {
+        let close = |actual: Option<f64>, expected: f64| (actual.unwrap() - expected).abs() <= expected.abs() * 1e-9;
+        let wavelength: Quantity<Metre> = Quantity::new(5.0e-7).with_uncertainty(2.0e-9);
+
+        assert!(close(wavelength.uncertainty_in("nm"), 2.0));
+        // 0.4% of 20000 cm⁻¹
+        assert!(close(wavelength.uncertainty_in("cm^-1"), 80.0));
+        assert_eq!(Quantity::<Metre>::new(5.0e-7).uncertainty_in("nm"), None);
+    }
+}
@@ -1,25 +1,177 @@
 use std::io::{stdin, stdout, Write};
 
-pub fn prompt_sigfigs() -> usize {
-    read_usize("Enter the number of significant figures needed: ") - 1
-}
+use crate::configuration::ShellType;
+use crate::quantity::*;
 
 pub fn read_i32(prompt: &str) -> i32 {
     prompt_string(prompt).parse().expect("Please type a number!")
 }
 
-fn read_usize(prompt: &str) -> usize {
+pub fn read_f64(prompt: &str) -> f64 {
     prompt_string(prompt).parse().expect("Please type a number!")
 }
 
-pub fn read_f64(prompt: &str) -> f64 {
-    prompt_string(prompt).parse().expect("Please type a number!")
+// Reads a plain number, taking its significant figures from how it was typed (e.g. "2.50" has 3)
+pub fn read_measured<U>(prompt: &str) -> Quantity<U> {
+    let number = prompt_string(prompt);
+
+    Quantity::measured(number.parse().expect("Please type a number!"), count_significant_figures(&number))
 }
 
 pub fn read_string(prompt: &str) -> String {
     prompt_string(prompt)
 }
 
+// Reads an element or ion, returning its symbol and charge (e.g. "Fe3+" -> ("Fe", 3))
+pub fn read_species(prompt: &str) -> Option<(String, i32)> {
+    parse_species(&prompt_string(prompt))
+}
+
+// No ion can have a charge larger than the heaviest element's atomic number
+const MAX_CHARGE: i32 = 118;
+
+// Parses an element symbol followed by an optional charge (e.g. "Fe", "Cu+", "O2-", "Fe^3+", "Fe³⁺")
+// Returns None for charges larger than any ion could have
+pub fn parse_species(input: &str) -> Option<(String, i32)> {
+    let symbol_length = input.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(input.len());
+    let (symbol, charge) = input.split_at(symbol_length);
+    let charge = charge.strip_prefix('^').unwrap_or(charge);
+
+    if symbol.is_empty() {
+        return None;
+    }
+
+    // Superscript charges are read the same as plain ones
+    let charge: String = charge.chars().map(|c| match "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().position(|superscript| superscript == c) {
+        Some(digit) => char::from_digit(digit as u32, 10).unwrap(),
+        None => match c {
+            '⁺' => '+',
+            '⁻' => '-',
+            _ => c,
+        },
+    }).collect();
+
+    let charge = if charge.is_empty() {
+        0
+    } else {
+        let (magnitude, sign) = match (charge.strip_suffix('+'), charge.strip_suffix('-')) {
+            (Some(magnitude), _) => (magnitude, 1),
+            (_, Some(magnitude)) => (magnitude, -1),
+            _ => return None,
+        };
+        if !magnitude.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let magnitude: i32 = if magnitude.is_empty() { 1 } else { magnitude.parse().ok()? };
+        if magnitude > MAX_CHARGE {
+            return None;
+        }
+
+        sign * magnitude
+    };
+
+    Some((symbol.to_string(), charge))
+}
+
+// Reads a subshell, returning its n and shell type (e.g. "3d" -> (3, D))
+pub fn read_subshell(prompt: &str) -> Option<(u32, ShellType)> {
+    parse_subshell(&prompt_string(prompt))
+}
+
+pub fn parse_subshell(input: &str) -> Option<(u32, ShellType)> {
+    let letter = input.chars().last()?;
+    let n = input[..input.len() - letter.len_utf8()].parse().ok()?;
+
+    Some((n, ShellType::from_letter(letter.to_ascii_lowercase())?))
+}
+
+// Reads a subshell and its electron count, returning the shell type and count (e.g. "d3" -> (D, 3))
+pub fn read_subshell_occupancy(prompt: &str) -> Option<(ShellType, u32)> {
+    parse_subshell_occupancy(&prompt_string(prompt))
+}
+
+// Parses a subshell with an electron count, optionally preceded by n (e.g. "p2", "3d3", "4f⁷")
+// The electron count must fit in the subshell
+pub fn parse_subshell_occupancy(input: &str) -> Option<(ShellType, u32)> {
+    let input = input.trim_start_matches(|c: char| c.is_ascii_digit());
+    let mut chars = input.chars();
+    let shell_type = ShellType::from_letter(chars.next()?.to_ascii_lowercase())?;
+    let electrons: String = chars.map(|c| match "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().position(|superscript| superscript == c) {
+        Some(digit) => char::from_digit(digit as u32, 10).unwrap(),
+        None => c,
+    }).collect();
+    let electrons = electrons.parse().ok()?;
+
+    if electrons > 2 * (2 * shell_type.l() + 1) {
+        return None;
+    }
+
+    Some((shell_type, electrons))
+}
+
+// Reads a number with an optional unit (e.g. "500 nm", "3.2 eV", "4.5e14 s^-1"), converted to SI units
+// A bare number is taken to be in the default unit, and the significant figures are taken from how it was typed
+pub fn read_quantity<U: ConvertibleUnit>(prompt: &str, default_unit: &str) -> Quantity<U> {
+    parse_quantity(&prompt_string(prompt), default_unit).expect("Please type a number with a supported unit!")
+}
+
+// Splits typed input into the number as written, its value and the rest (the unit)
+// The number is the longest prefix that parses, so "3.2eV" is 3.2 eV rather than a broken exponent
+fn split_number(input: &str) -> Option<(&str, f64, &str)> {
+    input.char_indices()
+        .map(|(index, _)| index)
+        .chain([input.len()])
+        .rev()
+        .find_map(|index| {
+            let number = input[..index].trim();
+            Some((number, number.parse::<f64>().ok()?, input[index..].trim()))
+        })
+}
+
+// Parses a number with an optional unit and ± uncertainty (e.g. "500 ± 2 nm", "500 nm +/- 2 nm")
+// The unit may follow the value, the uncertainty or both, but both must then agree
+// Returns None for values that aren't finite once converted (e.g. "inf", "NaN", "1e1000" or "0 cm^-1")
+pub fn parse_quantity<U: ConvertibleUnit>(input: &str, default_unit: &str) -> Option<Quantity<U>> {
+    let (measured, uncertainty) = match input.split_once('±').or_else(|| input.split_once("+/-")) {
+        Some((measured, uncertainty)) => (measured, Some(split_number(uncertainty)?)),
+        None => (input, None),
+    };
+    let (number, value, unit) = split_number(measured)?;
+
+    let unit = match (normalize_unit(unit), uncertainty.map(|(_, _, unit)| normalize_unit(unit))) {
+        (unit, Some(uncertainty_unit)) if !unit.is_empty() && !uncertainty_unit.is_empty() && unit != uncertainty_unit => return None,
+        (unit, _) if !unit.is_empty() => unit,
+        (_, Some(uncertainty_unit)) if !uncertainty_unit.is_empty() => uncertainty_unit,
+        _ => String::from(default_unit),
+    };
+
+    let quantity = Quantity::measured(U::convert(value, &unit)?, count_significant_figures(number));
+
+    let quantity = match uncertainty {
+        // Keep the relative uncertainty, which also handles reciprocal units like cm⁻¹ to first order
+        Some((_, uncertainty, _)) if value != 0.0 => quantity.with_uncertainty((quantity.value() * uncertainty / value).abs()),
+        Some((_, uncertainty, _)) => quantity.with_uncertainty(U::convert(uncertainty, &unit)?.abs()),
+        None => quantity,
+    };
+
+    quantity.is_finite().then_some(quantity)
+}
+
+// Counts the significant figures in a typed number (e.g. "3.20e14" -> 3, "0.0045" -> 2)
+// Trailing zeros only count when there is a decimal point, so "500" has 1 but "500." has 3
+pub fn count_significant_figures(number: &str) -> u32 {
+    let mantissa = number.trim_start_matches(['+', '-'])
+        .split(['e', 'E'])
+        .next()
+        .unwrap_or_default();
+    let digits = mantissa.replace('.', "");
+    let digits = digits.trim_start_matches('0');
+    let digits = if mantissa.contains('.') { digits } else { digits.trim_end_matches('0') };
+
+    // Zero itself still has one significant figure
+    (digits.chars().filter(char::is_ascii_digit).count() as u32).max(1)
+}
+
 fn prompt_string(prompt: &str) -> String {
     print!("{}", prompt);
     stdout().flush().expect("Failed to flush stdout");
@@ -27,3 +179,107 @@ fn prompt_string(prompt: &str) -> String {
     stdin().read_line(&mut input).expect("Failed to read line");
     input.trim().to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_species() {
+        assert_eq!(parse_species("Fe"), Some((String::from("Fe"), 0)));
+        assert_eq!(parse_species("Cu+"), Some((String::from("Cu"), 1)));
+        assert_eq!(parse_species("Fe3+"), Some((String::from("Fe"), 3)));
+        assert_eq!(parse_species("O2-"), Some((String::from("O"), -2)));
+        assert_eq!(parse_species("Fe^3+"), Some((String::from("Fe"), 3)));
+        assert_eq!(parse_species("Fe³⁺"), Some((String::from("Fe"), 3)));
+        assert_eq!(parse_species("O²⁻"), Some((String::from("O"), -2)));
+        assert_eq!(parse_species("Fe3"), None);
+        assert_eq!(parse_species("Fe³"), None);
+        assert_eq!(parse_species("Fe→"), None);
+        assert_eq!(parse_species("Fe-3+"), None);
+        assert_eq!(parse_species("Fe2147483647-"), None);
+        assert_eq!(parse_species("Fe99999999999+"), None);
+        assert_eq!(parse_species("3+"), None);
+    }
+
+    #[test]
+    fn test_parse_subshell() {
+        assert_eq!(parse_subshell("1s"), Some((1, ShellType::S)));
+        assert_eq!(parse_subshell("3d"), Some((3, ShellType::D)));
+        assert_eq!(parse_subshell("4F"), Some((4, ShellType::F)));
+        assert_eq!(parse_subshell("d"), None);
+        assert_eq!(parse_subshell("2x"), None);
+    }
+
+    #[test]
+    fn test_parse_subshell_occupancy() {
+        assert_eq!(parse_subshell_occupancy("p2"), Some((ShellType::P, 2)));
+        assert_eq!(parse_subshell_occupancy("3d3"), Some((ShellType::D, 3)));
+        assert_eq!(parse_subshell_occupancy("4f⁷"), Some((ShellType::F, 7)));
+        assert_eq!(parse_subshell_occupancy("p7"), None);
+        assert_eq!(parse_subshell_occupancy("p"), None);
+    }
+
+    #[test]
+    fn test_parse_quantity() {
+        let metres = |input: &str| parse_quantity::<Metre>(input, "m").map(Quantity::value);
+        assert_eq!(metres("0.5"), Some(0.5));
+        assert!((metres("500 nm").unwrap() - 5.0e-7).abs() < 1e-18);
+        assert!((metres("500nm").unwrap() - 5.0e-7).abs() < 1e-18);
+        assert!((metres("2.5 μm").unwrap() - 2.5e-6).abs() < 1e-18);
+        assert!((metres("20000 cm⁻¹").unwrap() - 5.0e-7).abs() < 1e-18);
+        assert!((metres("20000 cm-1").unwrap() - 5.0e-7).abs() < 1e-18);
+        assert_eq!(metres("500 nmol"), None);
+        assert_eq!(metres("inf nm"), None);
+        assert_eq!(metres("NaN"), None);
+        assert_eq!(metres("1e1000 m"), None);
+        assert_eq!(metres("0 cm-1"), None);
+        assert_eq!(metres("500 ± inf nm"), None);
+        assert_eq!(metres("nm"), None);
+
+        let hertz = |input: &str| parse_quantity::<Hertz>(input, "Hz").map(Quantity::value);
+        assert_eq!(hertz("4.5e14 s^-1"), Some(4.5e14));
+        assert_eq!(hertz("4.5e14 s⁻¹"), Some(4.5e14));
+        assert_eq!(hertz("4.5e14 1/s"), Some(4.5e14));
+        assert_eq!(hertz("600 THz"), Some(6.0e14));
+
+        let joules = |input: &str| parse_quantity::<Joule>(input, "J").map(Quantity::value);
+        assert!((joules("3.2 eV").unwrap() - 5.127e-19).abs() < 1e-22);
+        assert!((joules("3.2eV").unwrap() - 5.127e-19).abs() < 1e-22);
+        assert_eq!(joules("1e-19 J/photon"), Some(1e-19));
+
+        let molar = |input: &str| parse_quantity::<JoulePerMole>(input, "kJ/mol").map(Quantity::value);
+        assert_eq!(molar("250"), Some(250_000.0));
+        assert!((molar("10 kcal/mol").unwrap() - 41_840.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_count_significant_figures() {
+        let cases = [("3.20e14", 3), ("4.5e14", 2), ("1e15", 1), ("500", 1), ("500.", 3), ("500.0", 4), ("0.0045", 2), ("0.00450", 3), ("-2.178E-18", 4), ("0", 1), ("1002", 4)];
+
+        for (number, expected) in cases {
+            assert_eq!(count_significant_figures(number), expected, "{}", number);
+        }
+
+        assert_eq!(parse_quantity::<Metre>("500. nm", "m").unwrap().significant_figures(), Some(3));
+    }
+
+    #[test]
+    fn test_parse_uncertainty() {
+        let uncertainty = |input: &str| parse_quantity::<Metre>(input, "m").map(|quantity| quantity.uncertainty());
+        let close = |actual: Option<Option<f64>>, expected: f64| (actual.unwrap().unwrap() - expected).abs() <= expected * 1e-9;
+
+        assert!(close(uncertainty("500 ± 2 nm"), 2.0e-9));
+        assert!(close(uncertainty("500±2nm"), 2.0e-9));
+        assert!(close(uncertainty("500 nm +/- 2 nm"), 2.0e-9));
+        assert!(close(uncertainty("500 nm ± 2"), 2.0e-9));
+        assert!(close(uncertainty("0.5 ± 0.01"), 0.01));
+        // 1% of 20000 cm⁻¹ is 1% of 500 nm
+        assert!(close(uncertainty("20000 ± 200 cm^-1"), 5.0e-9));
+        assert_eq!(uncertainty("500 nm"), Some(None));
+        assert_eq!(uncertainty("500 nm ± 2 mm"), None);
+        assert_eq!(uncertainty("500 ± nm"), None);
+
+        assert!((parse_quantity::<Metre>("500 ± 2 nm", "m").unwrap().value() - 5.0e-7).abs() < 1e-18);
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::configuration::*;
+use crate::constants;
+
+// A single row of the Slater's rules breakdown (e.g. 18 electrons in n - 1, each shielding 0.85)
+pub struct SlaterContribution {
+    pub description: String,
+    pub electrons: u32,
+    pub factor: f64,
+}
+
+impl SlaterContribution {
+    pub fn shielding(&self) -> f64 {
+        self.electrons as f64 * self.factor
+    }
+}
+
+// The shielding felt by one electron of an atom or ion, according to Slater's rules
+// Electrons are grouped as [1s] [2s, 2p] [3s, 3p] [3d] [4s, 4p] [4d] [4f] [5s, 5p] [5d] [5f] ...
+// For an s or p electron, the other electrons shield it by
+    // 0.35 each in the same group (0.30 for 1s)
+    // 0.85 each with principal quantum number n - 1
+    // 1.00 each with principal quantum number n - 2 or lower
+// For a d or f electron, the other electrons shield it by
+    // 0.35 each in the same group
+    // 1.00 each in any group to the left
+// Electrons in groups to the right do not shield at all
+pub struct SlaterShielding {
+    pub atomic_number: u32,
+    pub n: u32,
+    pub shell_type: ShellType,
+    pub contributions: Vec<SlaterContribution>,
+}
+
+impl SlaterShielding {
+    // Applies Slater's rules to an electron in the given subshell
+    // Returns None if the subshell has no electrons in it
+    pub fn new(config: &ElectronConfiguration, n: u32, shell_type: ShellType) -> Option<Self> {
+        if config.occupancy(n, shell_type) == 0 {
+            return None;
+        }
+
+        let group = Self::group(n, shell_type);
+        let mut same_group = 0;
+        let mut n_minus_one = 0;
+        let mut lower = 0;
+
+        for (shell_n, shell) in config.iter() {
+            let shell_group = Self::group(shell_n, shell.shell_type);
+
+            if shell_group == group {
+                same_group += shell.electrons;
+            } else if shell_group < group {
+                match shell_type {
+                    ShellType::S | ShellType::P if shell_n + 1 == n => n_minus_one += shell.electrons,
+                    _ => lower += shell.electrons,
+                }
+            }
+        }
+
+        // The electron doesn't shield itself
+        same_group -= 1;
+
+        let mut contributions = vec![SlaterContribution {
+            description: format!("Same group ({})", Self::group_name(n, shell_type)),
+            electrons: same_group,
+            factor: if n == 1 { 0.30 } else { 0.35 },
+        }];
+
+        match shell_type {
+            // Nothing lies below the first shell
+            ShellType::S | ShellType::P if n == 1 => {}
+            ShellType::S | ShellType::P => {
+                contributions.push(SlaterContribution {
+                    description: format!("n - 1 (n = {})", n - 1),
+                    electrons: n_minus_one,
+                    factor: 0.85,
+                });
+                contributions.push(SlaterContribution {
+                    description: String::from("n - 2 and lower"),
+                    electrons: lower,
+                    factor: 1.00,
+                });
+            }
+            ShellType::D | ShellType::F => {
+                contributions.push(SlaterContribution {
+                    description: String::from("Groups to the left"),
+                    electrons: lower,
+                    factor: 1.00,
+                });
+            }
+        }
+
+        Some(Self {
+            atomic_number: config.atomic_number(),
+            n,
+            shell_type,
+            contributions,
+        })
+    }
+
+    // Gets the shielding constant (S)
+    pub fn shielding(&self) -> f64 {
+        self.contributions.iter().map(SlaterContribution::shielding).sum()
+    }
+
+    // Gets the effective nuclear charge
+    // Uses the formula Z_eff = Z - S, where
+        // Z is the atomic number
+        // S is the shielding constant
+    pub fn effective_nuclear_charge(&self) -> f64 {
+        self.atomic_number as f64 - self.shielding()
+    }
+
+    // Orders Slater groups from left to right; s and p share a group, while d and f each get their own
+    fn group(n: u32, shell_type: ShellType) -> (u32, u32) {
+        match shell_type {
+            ShellType::S | ShellType::P => (n, 0),
+            ShellType::D => (n, 1),
+            ShellType::F => (n, 2),
+        }
+    }
+
+    fn group_name(n: u32, shell_type: ShellType) -> String {
+        match shell_type {
+            ShellType::S | ShellType::P if n == 1 => String::from("1s"),
+            ShellType::S | ShellType::P => format!("{}s, {}p", n, n),
+            _ => format!("{}{}", n, shell_type.letter()),
+        }
+    }
+}
+
+impl Display for SlaterShielding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let element = constants::element_name(self.atomic_number).unwrap_or_default();
+
+        writeln!(f, "Slater's rules for a {}{} electron of {} (Z = {})", self.n, self.shell_type.letter(), element, self.atomic_number)?;
+
+        for contribution in &self.contributions {
+            writeln!(f, "    {}: {} × {:.2} = {:.2}", contribution.description, contribution.electrons, contribution.factor, contribution.shielding())?;
+        }
+
+        writeln!(f, "Shielding constant (S): {:.2}", self.shielding())?;
+        write!(f, "Effective nuclear charge (Z_eff = Z - S): {:.2}", self.effective_nuclear_charge())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effective_nuclear_charge(element: &str, n: u32, shell_type: ShellType) -> f64 {
+        let config = ElectronConfiguration::new(element).unwrap();
+        SlaterShielding::new(&config, n, shell_type).unwrap().effective_nuclear_charge()
+    }
+
+    #[test]
+    fn test_effective_nuclear_charge() {
+        let cases = [
+            ("H", 1, ShellType::S, 1.00),
+            ("He", 1, ShellType::S, 1.70),
+            ("N", 2, ShellType::P, 3.90),
+            ("O", 2, ShellType::P, 4.55),
+            ("Na", 3, ShellType::S, 2.20),
+            ("K", 4, ShellType::S, 2.20),
+            ("Zn", 4, ShellType::S, 4.35),
+            ("Zn", 3, ShellType::D, 8.85),
+            ("Fe", 3, ShellType::D, 6.25),
+        ];
+
+        for (element, n, shell_type, z_eff) in cases {
+            assert!((effective_nuclear_charge(element, n, shell_type) - z_eff).abs() < 1e-9, "{} {}{}", element, n, shell_type.letter());
+        }
+    }
+
+    #[test]
+    fn test_first_shell() {
+        let shielding = SlaterShielding::new(&ElectronConfiguration::new("He").unwrap(), 1, ShellType::S).unwrap();
+
+        assert_eq!(shielding.contributions.len(), 1);
+        assert!(!shielding.to_string().contains("n - 1"));
+    }
+
+    #[test]
+    fn test_unoccupied_subshell() {
+        let config = ElectronConfiguration::new("Na").unwrap();
+        assert!(SlaterShielding::new(&config, 3, ShellType::P).is_none());
+    }
+}
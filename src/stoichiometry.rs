@@ -0,0 +1,457 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::constants::*;
+use crate::equation::*;
+use crate::formula::*;
+use crate::quantity::*;
+use crate::readers::*;
+
+// The unit an amount of a species is given in
+#[derive(Clone, Copy, PartialEq)]
+pub enum AmountUnit {
+    Grams,
+    Moles,
+    Particles,
+}
+
+impl AmountUnit {
+    pub fn prompt() -> Self {
+        match read_i32("Enter 1 for grams, 2 for moles or 3 for particles: ") {
+            1 => AmountUnit::Grams,
+            3 => AmountUnit::Particles,
+            _ => AmountUnit::Moles,
+        }
+    }
+
+    // Converts an amount of the given species in this unit to moles
+    // Masses are limited by the significant figures of the molar mass as well as the amount
+    // Returns None for a mass of electrons, which have no molar mass to divide by
+    pub fn to_moles(self, amount: Quantity<Dimensionless>, formula: &Formula) -> Option<Quantity<Mole>> {
+        match self {
+            AmountUnit::Grams => Some(amount * Quantity::<Gram>::new(1.0) / formula.measured_molar_mass()?),
+            AmountUnit::Moles => Some(amount * Quantity::<Mole>::new(1.0)),
+            AmountUnit::Particles => Some(amount / AVOGADRO),
+        }
+    }
+}
+
+impl Display for AmountUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            AmountUnit::Grams => "g",
+            AmountUnit::Moles => "mol",
+            AmountUnit::Particles => "particles",
+        })
+    }
+}
+
+// Reads a balanced equation, balancing it if needed
+// Returns None if the equation is invalid or cannot be balanced
+pub fn prompt_equation() -> Option<BalancedEquation> {
+    let reaction = Reaction::parse(&read_string("Enter the equation (e.g. C3H8 + O2 -> CO2 + H2O): "))?;
+
+    match reaction.balance() {
+        Balance::Balanced(equation) => Some(equation),
+        _ => None,
+    }
+}
+
+// Formats a quantity's value in scientific notation to its significant figures, or in full if it is exact
+pub fn format_measured<U>(quantity: Quantity<U>) -> String {
+    match quantity.significant_figures() {
+        Some(significant_figures) => format!("{:.1$e}", quantity.value(), significant_figures as usize - 1),
+        None => format!("{:e}", quantity.value()),
+    }
+}
+
+// Explains the conversion from moles of one species to moles of another, then to grams and particles
+// Uses the mole ratio from the balanced equation, where
+    // n(wanted) = n(given) × (coefficient of wanted / coefficient of given)
+// The coefficients are exact, so each result keeps the significant figures of the given amount and molar masses
+pub fn mole_ratio_chain(given: &(u32, Formula), given_moles: Quantity<Mole>, wanted: &(u32, Formula)) -> String {
+    let (given_coefficient, given_formula) = given;
+    let (wanted_coefficient, wanted_formula) = wanted;
+    let wanted_moles = given_moles * *wanted_coefficient as f64 / *given_coefficient as f64;
+    let indent = " ".repeat(wanted_formula.text.chars().count() + 2);
+
+    let mut lines = vec![format!(
+        "{wanted_formula}: {} mol {given_formula} × ({wanted_coefficient} mol {wanted_formula} / {given_coefficient} mol {given_formula}) = {} mol {wanted_formula}",
+        format_measured(given_moles),
+        format_measured(wanted_moles),
+    )];
+
+    // Electrons have no molar mass worth reporting
+    if let Some(molar_mass) = wanted_formula.measured_molar_mass() {
+        lines.push(format!(
+            "{indent}{} mol {wanted_formula} × {} g/mol = {} g {wanted_formula}",
+            format_measured(wanted_moles),
+            format_measured(molar_mass),
+            format_measured(wanted_moles * molar_mass),
+        ));
+    }
+
+    lines.push(format!(
+        "{indent}{} mol {wanted_formula} × {AVOGADRO:e} = {} particles {wanted_formula}",
+        format_measured(wanted_moles),
+        format_measured(wanted_moles * AVOGADRO),
+    ));
+
+    lines.join("\n")
+}
+
+// The theoretical amount of every species in a reaction, from a known amount of one of them
+// The given amount keeps the significant figures it was typed with, which carry through to every result
+pub struct Stoichiometry {
+    pub equation: BalancedEquation,
+    pub given: String,
+    pub amount: Quantity<Dimensionless>,
+    pub unit: AmountUnit,
+}
+
+impl Stoichiometry {
+    // Returns None if the species isn't in the equation, or its amount can't be given in the unit
+    pub fn new(equation: BalancedEquation, given: &str, amount: Quantity<Dimensionless>, unit: AmountUnit) -> Option<Self> {
+        let (_, formula) = equation.find(given)?;
+        unit.to_moles(amount, formula)?;
+
+        Some(Self {
+            equation,
+            given: given.trim().to_string(),
+            amount,
+            unit,
+        })
+    }
+
+    // Returns None if the equation is invalid or unbalanceable, the species isn't in it, or it is a mass of electrons
+    pub fn prompt() -> Option<Self> {
+        let equation = prompt_equation()?;
+        let given = read_string("Enter the species with a known amount: ");
+        let amount = read_measured("Enter the amount: ");
+        let unit = AmountUnit::prompt();
+
+        Self::new(equation, &given, amount, unit)
+    }
+
+    // Gets the moles of the given species
+    pub fn given_moles(&self) -> Quantity<Mole> {
+        let (_, formula) = self.equation.find(&self.given).expect("Given species should be in the equation");
+
+        self.unit.to_moles(self.amount, formula).expect("Given amount should be checked when it is set")
+    }
+
+    // Gets the theoretical moles of the species with the given formula
+    pub fn moles(&self, formula: &str) -> Option<Quantity<Mole>> {
+        let (given_coefficient, _) = self.equation.find(&self.given)?;
+        let (coefficient, _) = self.equation.find(formula)?;
+
+        Some(self.given_moles() * *coefficient as f64 / *given_coefficient as f64)
+    }
+}
+
+impl Display for Stoichiometry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let given = self.equation.find(&self.given).expect("Given species should be in the equation");
+        let (_, given_formula) = given;
+        let given_moles = self.given_moles();
+
+        writeln!(f, "Balanced equation: {}", self.equation)?;
+        write!(f, "Given: {} {} {}", format_measured(self.amount), self.unit, given_formula)?;
+
+        // Converts the given amount to moles before applying the mole ratio
+        let factor = match self.unit {
+            AmountUnit::Grams => given_formula.measured_molar_mass().map(|molar_mass| format!("{} g/mol", format_measured(molar_mass))),
+            AmountUnit::Particles => Some(format!("{:e}", AVOGADRO)),
+            AmountUnit::Moles => None,
+        };
+
+        if let Some(factor) = factor {
+            write!(
+                f,
+                "\n       {} {} {} ÷ {} = {} mol {}",
+                format_measured(self.amount), self.unit, given_formula, factor, format_measured(given_moles), given_formula,
+            )?;
+        }
+
+        for species in self.equation.species().filter(|(_, formula)| formula.text != given_formula.text) {
+            write!(f, "\n{}", mole_ratio_chain(given, given_moles, species))?;
+        }
+
+        Ok(())
+    }
+}
+
+// The limiting reactant of a reaction, from the available amount of every reactant
+// Each amount keeps the significant figures it was typed with, which carry through to every result
+pub struct LimitingReactant {
+    pub equation: BalancedEquation,
+    pub available_moles: Vec<Quantity<Mole>>,
+    pub actual_yield: Option<(String, Quantity<Gram>)>,
+}
+
+impl LimitingReactant {
+    // Takes the available amount of each reactant, in the order they appear in the equation
+    // Returns None if an amount is missing for any reactant, or is a mass of electrons
+    pub fn new(equation: BalancedEquation, amounts: &[(Quantity<Dimensionless>, AmountUnit)]) -> Option<Self> {
+        if amounts.len() != equation.reactants.len() {
+            return None;
+        }
+
+        let available_moles = equation.reactants.iter()
+            .zip(amounts)
+            .map(|((_, formula), (amount, unit))| unit.to_moles(*amount, formula))
+            .collect::<Option<_>>()?;
+
+        Some(Self {
+            equation,
+            available_moles,
+            actual_yield: None,
+        })
+    }
+
+    // Records a measured yield of a product to compute the percent yield
+    // Returns None if the product isn't in the equation, or is electrons (which have no mass to measure)
+    pub fn with_actual_yield(mut self, product: &str, grams: Quantity<Gram>) -> Option<Self> {
+        let (_, formula) = self.equation.products.iter().find(|(_, formula)| formula.text == product.trim())?;
+        formula.measured_molar_mass()?;
+        self.actual_yield = Some((product.trim().to_string(), grams));
+
+        Some(self)
+    }
+
+    // Returns None if the equation is invalid or unbalanceable, or the measured product isn't in it
+    pub fn prompt() -> Option<Self> {
+        let equation = prompt_equation()?;
+        println!("Balanced equation: {}", equation);
+
+        let amounts: Vec<(Quantity<Dimensionless>, AmountUnit)> = equation.reactants.iter()
+            .map(|(_, formula)| {
+                let amount = read_measured(&format!("Enter the available amount of {}: ", formula));
+                (amount, AmountUnit::prompt())
+            })
+            .collect();
+
+        let limiting_reactant = Self::new(equation, &amounts)?;
+        let product = read_string("Enter the product with a measured yield (or leave blank): ");
+
+        if product.trim().is_empty() {
+            Some(limiting_reactant)
+        } else {
+            limiting_reactant.with_actual_yield(&product, read_measured("Enter the actual yield (in g): "))
+        }
+    }
+
+    // Gets the moles of reaction each reactant could support on its own
+    // Uses the formula ξ = n / ν, where
+        // ξ is the extent of reaction (mol)
+        // n is the available amount of the reactant (mol)
+        // ν is the coefficient of the reactant
+    fn extents(&self) -> impl Iterator<Item = Quantity<Mole>> + '_ {
+        self.equation.reactants.iter()
+            .zip(&self.available_moles)
+            .map(|((coefficient, _), moles)| *moles / *coefficient as f64)
+    }
+
+    // Gets the index of the reactant that runs out first
+    pub fn limiting_index(&self) -> usize {
+        self.extents()
+            .enumerate()
+            .fold((0, f64::INFINITY), |(best, lowest), (index, extent)| if extent.value() < lowest { (index, extent.value()) } else { (best, lowest) })
+            .0
+    }
+
+    // Gets the reactant that runs out first
+    pub fn limiting(&self) -> &Formula {
+        &self.equation.reactants[self.limiting_index()].1
+    }
+
+    // Gets the extent of reaction allowed by the limiting reactant
+    pub fn extent(&self) -> Quantity<Mole> {
+        self.extents().nth(self.limiting_index()).expect("A balanced equation should have reactants")
+    }
+
+    // Gets the moles of each reactant left over once the limiting reactant is used up
+    pub fn excess_moles(&self) -> Vec<Quantity<Mole>> {
+        let extent = self.extent();
+
+        self.equation.reactants.iter()
+            .zip(&self.available_moles)
+            .map(|((coefficient, _), moles)| {
+                let excess = *moles - extent * *coefficient as f64;
+
+                // Rounding can leave slightly less than none, which is really none left
+                if excess.value() < 0.0 { -excess * 0.0 } else { excess }
+            })
+            .collect()
+    }
+
+    // Gets the theoretical yield of a product
+    pub fn theoretical_yield(&self, product: &str) -> Option<Quantity<Mole>> {
+        let (coefficient, _) = self.equation.products.iter().find(|(_, formula)| formula.text == product.trim())?;
+
+        Some(self.extent() * *coefficient as f64)
+    }
+
+    // Gets the percent yield of the measured product
+    // Uses the formula percent yield = actual / theoretical × 100, where both yields are in g
+    pub fn percent_yield(&self) -> Option<Quantity<Dimensionless>> {
+        let (product, grams) = self.actual_yield.as_ref()?;
+        let (_, formula) = self.equation.products.iter().find(|(_, formula)| &formula.text == product)?;
+
+        Some(*grams / (self.theoretical_yield(product)? * formula.measured_molar_mass()?) * 100.0)
+    }
+}
+
+// Formats the mass of an amount of a species, or nothing for electrons, which have no mass worth reporting
+fn format_mass(moles: Quantity<Mole>, formula: &Formula) -> String {
+    match formula.measured_molar_mass() {
+        Some(molar_mass) => format!(" ({} g)", format_measured(moles * molar_mass)),
+        None => String::new(),
+    }
+}
+
+impl Display for LimitingReactant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "Balanced equation: {}", self.equation)?;
+
+        for (((coefficient, formula), moles), extent) in self.equation.reactants.iter().zip(&self.available_moles).zip(self.extents()) {
+            writeln!(f, "{formula}: {} mol ÷ {coefficient} = {} mol of reaction", format_measured(*moles), format_measured(extent))?;
+        }
+
+        write!(f, "Limiting reactant: {}", self.limiting())?;
+
+        let extent = self.extent();
+        let limiting_index = self.limiting_index();
+
+        for (index, ((coefficient, formula), excess)) in self.equation.reactants.iter().zip(self.excess_moles()).enumerate() {
+            if index != limiting_index {
+                write!(
+                    f,
+                    "\nExcess {formula}: {} mol - {} mol × {coefficient} = {} mol{} remaining",
+                    format_measured(self.available_moles[index]),
+                    format_measured(extent),
+                    format_measured(excess),
+                    format_mass(excess, formula),
+                )?;
+            }
+        }
+
+        for (coefficient, formula) in &self.equation.products {
+            let moles = extent * *coefficient as f64;
+            write!(
+                f,
+                "\nTheoretical yield of {formula}: {} mol × {coefficient} = {} mol{}",
+                format_measured(extent),
+                format_measured(moles),
+                format_mass(moles, formula),
+            )?;
+        }
+
+        if let (Some((product, grams)), Some(percent)) = (&self.actual_yield, self.percent_yield()) {
+            write!(f, "\nPercent yield of {product}: {} g actual, {}%", format_measured(*grams), format_measured(percent))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn propane_combustion() -> BalancedEquation {
+        match Reaction::parse("C3H8 + O2 -> CO2 + H2O").unwrap().balance() {
+            Balance::Balanced(equation) => equation,
+            _ => panic!("Equation should balance"),
+        }
+    }
+
+    #[test]
+    fn test_theoretical_moles() {
+        let stoichiometry = Stoichiometry::new(propane_combustion(), "C3H8", Quantity::measured(44.097, 5), AmountUnit::Grams).unwrap();
+
+        assert!((stoichiometry.given_moles().value() - 1.0).abs() < 1e-9);
+        assert!((stoichiometry.moles("O2").unwrap().value() - 5.0).abs() < 1e-9);
+        assert!((stoichiometry.moles("CO2").unwrap().value() - 3.0).abs() < 1e-9);
+        assert!((stoichiometry.moles("H2O").unwrap().value() - 4.0).abs() < 1e-9);
+        assert!(stoichiometry.moles("N2").is_none());
+    }
+
+    #[test]
+    fn test_amount_units() {
+        let water = Formula::parse("H2O").unwrap();
+
+        assert!((AmountUnit::Grams.to_moles(Quantity::new(18.015), &water).unwrap().value() - 1.0).abs() < 1e-9);
+        assert!((AmountUnit::Moles.to_moles(Quantity::new(2.0), &water).unwrap().value() - 2.0).abs() < 1e-9);
+        assert!((AmountUnit::Particles.to_moles(Quantity::new(AVOGADRO.value()), &water).unwrap().value() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_electrons() {
+        let reduction = || match Reaction::parse("Fe3+ + e- -> Fe2+").unwrap().balance() {
+            Balance::Balanced(equation) => equation,
+            _ => panic!("Equation should balance"),
+        };
+
+        // Electrons can be counted in moles or particles, but have no mass
+        assert!(Stoichiometry::new(reduction(), "e-", Quantity::measured(1.0, 1), AmountUnit::Grams).is_none());
+        assert!((Stoichiometry::new(reduction(), "e-", Quantity::measured(2.0, 1), AmountUnit::Moles).unwrap().moles("Fe2+").unwrap().value() - 2.0).abs() < 1e-9);
+        assert!(LimitingReactant::new(reduction(), &[(Quantity::measured(55.845, 5), AmountUnit::Grams), (Quantity::measured(1.0, 1), AmountUnit::Grams)]).is_none());
+
+        let equation = match Reaction::parse("Fe2+ -> Fe3+ + e-").unwrap().balance() {
+            Balance::Balanced(equation) => equation,
+            _ => panic!("Equation should balance"),
+        };
+        let limiting_reactant = LimitingReactant::new(equation, &[(Quantity::measured(1.0, 1), AmountUnit::Moles)]).unwrap();
+        assert!(limiting_reactant.to_string().ends_with("Theoretical yield of e-: 1e0 mol × 1 = 1e0 mol"));
+        assert!(limiting_reactant.with_actual_yield("e-", Quantity::measured(1.0, 1)).is_none());
+    }
+
+    #[test]
+    fn test_significant_figures() {
+        let equation = match Reaction::parse("H2 + O2 -> H2O").unwrap().balance() {
+            Balance::Balanced(equation) => equation,
+            _ => panic!("Equation should balance"),
+        };
+        let stoichiometry = Stoichiometry::new(equation, "H2", Quantity::measured(2.50, 3), AmountUnit::Grams).unwrap();
+
+        // The amount was typed to 3 significant figures, and the molar masses are known to more
+        assert_eq!(stoichiometry.given_moles().significant_figures(), Some(3));
+        assert!(stoichiometry.to_string().contains("\n    6.20e-1 mol O2 × 3.1998e1 g/mol = 1.98e1 g O2\n"));
+    }
+
+    #[test]
+    fn test_unknown_species() {
+        assert!(Stoichiometry::new(propane_combustion(), "CH4", Quantity::measured(1.0, 1), AmountUnit::Moles).is_none());
+    }
+
+    #[test]
+    fn test_limiting_reactant() {
+        // 1 mol C3H8 needs 5 mol O2, so 4 mol O2 runs out first
+        let amounts = [(Quantity::measured(1.000, 4), AmountUnit::Moles), (Quantity::measured(128.0, 4), AmountUnit::Grams)];
+        let limiting_reactant = LimitingReactant::new(propane_combustion(), &amounts).unwrap();
+
+        assert_eq!(limiting_reactant.limiting().text, "O2");
+        assert!((limiting_reactant.extent().value() - 0.8).abs() < 1e-3);
+        assert_eq!(limiting_reactant.extent().significant_figures(), Some(4));
+        assert!((limiting_reactant.excess_moles()[0].value() - 0.2).abs() < 1e-3);
+        assert!(limiting_reactant.excess_moles()[1].value().abs() < 1e-9);
+        assert!((limiting_reactant.theoretical_yield("CO2").unwrap().value() - 2.4).abs() < 1e-3);
+        assert!((limiting_reactant.theoretical_yield("H2O").unwrap().value() - 3.2).abs() < 1e-3);
+        assert!(limiting_reactant.theoretical_yield("O2").is_none());
+    }
+
+    #[test]
+    fn test_percent_yield() {
+        let amounts = [(Quantity::measured(1.00, 3), AmountUnit::Moles), (Quantity::measured(10.0, 3), AmountUnit::Moles)];
+
+        // 1 mol C3H8 gives 3 mol CO2, or 132.03 g
+        let percent_yield = LimitingReactant::new(propane_combustion(), &amounts).unwrap()
+            .with_actual_yield("CO2", Quantity::measured(66.0, 3)).unwrap()
+            .percent_yield().unwrap();
+        assert!((percent_yield.value() - 49.99).abs() < 0.01);
+        assert_eq!(percent_yield.significant_figures(), Some(3));
+
+        let limiting_reactant = LimitingReactant::new(propane_combustion(), &amounts).unwrap();
+        assert!(limiting_reactant.with_actual_yield("C3H8", Quantity::measured(1.0, 2)).is_none());
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::configuration::*;
+
+// A Russell-Saunders term symbol (²ˢ⁺¹L_J)
+// Spin and total angular momentum are stored doubled so that half-integer values stay exact
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TermSymbol {
+    pub two_s: u32,
+    pub l: u32,
+    pub two_j: Option<u32>,
+}
+
+impl TermSymbol {
+    // Gets the spin multiplicity (2S + 1)
+    pub fn multiplicity(&self) -> u32 {
+        self.two_s + 1
+    }
+
+    // Gets every level (J value) of the term, from |L - S| up to L + S
+    pub fn levels(&self) -> Vec<TermSymbol> {
+        let two_l = 2 * self.l;
+        let lowest = two_l.abs_diff(self.two_s);
+
+        (lowest..=two_l + self.two_s).step_by(2)
+            .map(|two_j| TermSymbol { two_j: Some(two_j), ..*self })
+            .collect()
+    }
+
+    // Gets the letter for a total orbital angular momentum (J is skipped)
+    fn l_letter(l: u32) -> char {
+        "SPDFGHIKLMNOQRTUV".chars().nth(l as usize).unwrap_or('?')
+    }
+}
+
+impl Display for TermSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}{}", superscript(self.multiplicity()), Self::l_letter(self.l))?;
+
+        match self.two_j {
+            Some(two_j) if two_j % 2 == 0 => write!(f, "{}", subscript(two_j / 2)),
+            Some(two_j) => write!(f, "{}/{}", subscript(two_j), subscript(2)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn superscript(value: u32) -> String {
+    value.to_string().chars().map(|digit| "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().nth(digit as usize - '0' as usize).unwrap()).collect()
+}
+
+fn subscript(value: u32) -> String {
+    value.to_string().chars().map(|digit| "₀₁₂₃₄₅₆₇₈₉".chars().nth(digit as usize - '0' as usize).unwrap()).collect()
+}
+
+// Where a ground state term comes from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TermSource {
+    // Hund's rules applied to the only open subshell, which are exact for its lowest term
+    HundsRules,
+    // Measured, for atoms with several open subshells
+    Experimental,
+    // Hund's rules applied to several open subshells at once, which can pick the wrong term
+    Approximate,
+}
+
+impl Display for TermSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", match self {
+            TermSource::HundsRules => "from Hund's rules",
+            TermSource::Experimental => "experimental",
+            TermSource::Approximate => "approximate, assuming the open subshells couple in parallel",
+        })
+    }
+}
+
+// Measured ground state terms of the neutral atoms with more than one open subshell, by atomic number
+// Source: NIST Atomic Spectra Database
+const MEASURED_GROUND_STATE_TERMS: [(u32, TermSymbol); 12] = [
+    (24, TermSymbol { two_s: 6, l: 0, two_j: Some(6) }), // Cr ⁷S₃
+    (41, TermSymbol { two_s: 5, l: 2, two_j: Some(1) }), // Nb ⁶D₁/₂
+    (42, TermSymbol { two_s: 6, l: 0, two_j: Some(6) }), // Mo ⁷S₃
+    (44, TermSymbol { two_s: 4, l: 3, two_j: Some(10) }), // Ru ⁵F₅
+    (45, TermSymbol { two_s: 3, l: 3, two_j: Some(9) }), // Rh ⁴F₉/₂
+    (58, TermSymbol { two_s: 0, l: 4, two_j: Some(8) }), // Ce ¹G₄
+    (64, TermSymbol { two_s: 8, l: 2, two_j: Some(4) }), // Gd ⁹D₂
+    (78, TermSymbol { two_s: 2, l: 2, two_j: Some(6) }), // Pt ³D₃
+    (91, TermSymbol { two_s: 3, l: 7, two_j: Some(11) }), // Pa ⁴K₁₁/₂
+    (92, TermSymbol { two_s: 4, l: 8, two_j: Some(12) }), // U ⁵L₆
+    (93, TermSymbol { two_s: 5, l: 8, two_j: Some(11) }), // Np ⁶L₁₁/₂
+    (96, TermSymbol { two_s: 8, l: 2, two_j: Some(4) }), // Cm ⁹D₂
+];
+
+// Gets the ground state term symbol of an atom or ion, and where it comes from
+// With at most one open subshell, Hund's rules give the lowest term exactly
+    // 1. The term with the highest multiplicity (S) is lowest in energy
+    // 2. Of those, the term with the highest L is lowest in energy
+    // 3. J = |L - S| if the subshell is at most half filled, otherwise J = L + S
+// Neutral atoms with several open subshells use the measured term instead
+// Otherwise the rules are applied to every open subshell at once, assuming their spins and orbital momenta align,
+// which is only an approximation (e.g. it gives ³H₄ for Ce, which is really ¹G₄)
+pub fn ground_state_term(config: &ElectronConfiguration) -> (TermSymbol, TermSource) {
+    let open_shells: Vec<(u32, &Shell)> = config.iter().filter(|(_, shell)| !shell.is_full()).collect();
+
+    if open_shells.len() > 1 && config.charge() == 0 {
+        if let Some((_, term)) = MEASURED_GROUND_STATE_TERMS.iter().find(|(atomic_number, _)| *atomic_number == config.atomic_number()) {
+            return (*term, TermSource::Experimental);
+        }
+    }
+
+    let mut two_s = 0;
+    let mut l = 0;
+    let mut more_than_half_filled = false;
+
+    // Shell::quantum_numbers fills the orbitals by Hund's rule, so the totals give the highest S, then highest L
+    for (n, shell) in &open_shells {
+        let quantum_numbers = shell.quantum_numbers(*n);
+
+        two_s += quantum_numbers.iter().map(|electron| (2.0 * electron.ms) as i32).sum::<i32>().unsigned_abs();
+        l += quantum_numbers.iter().map(|electron| electron.ml).sum::<i32>().unsigned_abs();
+        more_than_half_filled |= shell.electrons > shell.orbitals();
+    }
+
+    let two_j = if more_than_half_filled { 2 * l + two_s } else { (2 * l).abs_diff(two_s) };
+    let source = if open_shells.len() > 1 { TermSource::Approximate } else { TermSource::HundsRules };
+
+    (TermSymbol { two_s, l, two_j: Some(two_j) }, source)
+}
+
+// A single arrangement of electrons among the spin-orbitals of a subshell
+// Spin is stored doubled, as with TermSymbol
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Microstate {
+    pub ml: i32,
+    pub two_ms: i32,
+}
+
+// Gets every microstate of a subshell with the given l and electron count, as its total (ML, MS)
+pub fn microstates(l: u32, electrons: u32) -> Vec<Microstate> {
+    let spin_orbitals = 2 * (2 * l + 1);
+    let mut microstates = Vec::new();
+
+    // Each bit of the mask is one spin-orbital, so every mask with the right number of bits set is a microstate
+    for mask in 0u32..(1 << spin_orbitals) {
+        if mask.count_ones() != electrons {
+            continue;
+        }
+
+        let mut microstate = Microstate { ml: 0, two_ms: 0 };
+
+        for spin_orbital in (0..spin_orbitals).filter(|spin_orbital| mask & (1 << spin_orbital) != 0) {
+            microstate.ml += (spin_orbital / 2) as i32 - l as i32;
+            microstate.two_ms += if spin_orbital % 2 == 0 { 1 } else { -1 };
+        }
+
+        microstates.push(microstate);
+    }
+
+    microstates
+}
+
+// Gets every term arising from a subshell with the given l and electron count, lowest energy (by Hund's rules) first
+// Repeatedly takes the microstate with the highest ML (then MS), which must be the top of a term,
+// and removes the (2L + 1)(2S + 1) microstates belonging to that term
+pub fn terms(l: u32, electrons: u32) -> Vec<TermSymbol> {
+    let mut remaining = microstates(l, electrons);
+    let mut terms = Vec::new();
+
+    while let Some(top) = remaining.iter().copied().max_by_key(|microstate| (microstate.ml, microstate.two_ms)) {
+        let term = TermSymbol { two_s: top.two_ms as u32, l: top.ml as u32, two_j: None };
+
+        for ml in -top.ml..=top.ml {
+            for two_ms in (-top.two_ms..=top.two_ms).step_by(2) {
+                let index = remaining.iter()
+                    .position(|microstate| microstate.ml == ml && microstate.two_ms == two_ms)
+                    .expect("Every microstate of a term should be present");
+
+                remaining.swap_remove(index);
+            }
+        }
+
+        terms.push(term);
+    }
+
+    terms.sort_by_key(|term| (std::cmp::Reverse(term.two_s), std::cmp::Reverse(term.l)));
+
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ground_state(element: &str) -> String {
+        let (term, _) = ground_state_term(&ElectronConfiguration::new(element).unwrap());
+        term.to_string()
+    }
+
+    #[test]
+    fn test_ground_state_term() {
+        assert_eq!(ground_state("H"), "²S₁/₂");
+        assert_eq!(ground_state("He"), "¹S₀");
+        assert_eq!(ground_state("B"), "²P₁/₂");
+        assert_eq!(ground_state("C"), "³P₀");
+        assert_eq!(ground_state("N"), "⁴S₃/₂");
+        assert_eq!(ground_state("O"), "³P₂");
+        assert_eq!(ground_state("F"), "²P₃/₂");
+        assert_eq!(ground_state("Ti"), "³F₂");
+        assert_eq!(ground_state("Cr"), "⁷S₃");
+        assert_eq!(ground_state("Mn"), "⁶S₅/₂");
+        assert_eq!(ground_state("Fe"), "⁵D₄");
+        assert_eq!(ground_state("Ni"), "³F₄");
+        assert_eq!(ground_state("Cu"), "²S₁/₂");
+        assert_eq!(ground_state("Gd"), "⁹D₂");
+        assert_eq!(ground_state("U"), "⁵L₆");
+        assert_eq!(ground_state("Ce"), "¹G₄");
+    }
+
+    #[test]
+    fn test_ground_state_term_source() {
+        let source = |element, charge| ground_state_term(&ElectronConfiguration::ion(element, charge).unwrap()).1;
+
+        assert_eq!(source("Fe", 0), TermSource::HundsRules);
+        assert_eq!(source("Fe", 3), TermSource::HundsRules);
+        assert_eq!(source("Ce", 0), TermSource::Experimental);
+        assert_eq!(source("Cr", 0), TermSource::Experimental);
+        assert_eq!(source("Cr", -1), TermSource::HundsRules);
+        assert_eq!(source("Ce", 1), TermSource::Approximate);
+    }
+
+    #[test]
+    fn test_terms() {
+        let terms = |l, electrons| terms(l, electrons).iter().map(TermSymbol::to_string).collect::<Vec<_>>();
+
+        assert_eq!(microstates(1, 2).len(), 15);
+        assert_eq!(terms(1, 2), ["³P", "¹D", "¹S"]);
+        assert_eq!(terms(1, 3), ["⁴S", "²D", "²P"]);
+        assert_eq!(microstates(2, 2).len(), 45);
+        assert_eq!(terms(2, 2), ["³F", "³P", "¹G", "¹D", "¹S"]);
+        assert_eq!(microstates(2, 3).len(), 120);
+        assert_eq!(terms(2, 3), ["⁴F", "⁴P", "²H", "²G", "²F", "²D", "²D", "²P"]);
+    }
+
+    #[test]
+    fn test_levels() {
+        let levels = TermSymbol { two_s: 2, l: 1, two_j: None }.levels();
+        assert_eq!(levels.iter().map(TermSymbol::to_string).collect::<Vec<_>>(), ["³P₀", "³P₁", "³P₂"]);
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::constants::*;
+use crate::readers::*;
+
+// Explains the periodic trends between two elements, and checks them against the measured properties
+pub struct ElementComparison {
+    pub first: &'static Element,
+    pub second: &'static Element,
+}
+
+impl ElementComparison {
+    pub fn new(first: &'static Element, second: &'static Element) -> Self {
+        Self { first, second }
+    }
+
+    // Returns None if either element doesn't exist
+    pub fn prompt() -> Option<Self> {
+        let first = Element::lookup(&read_string("Enter the first element symbol, name or atomic number: "))?;
+        let second = Element::lookup(&read_string("Enter the second element symbol, name or atomic number: "))?;
+
+        Some(Self::new(first, second))
+    }
+
+    // Gets whether the second element is to the right of (1), left of (-1) or in line with (0) the first
+    fn across(&self) -> i32 {
+        // The f-block sits between groups 2 and 3, and follows atomic number within a period
+        let column = |element: &Element| element.group.map_or(2.5, f64::from);
+
+        if self.first.period == self.second.period {
+            self.second.atomic_number.cmp(&self.first.atomic_number) as i32
+        } else {
+            column(self.second).total_cmp(&column(self.first)) as i32
+        }
+    }
+
+    // Gets whether the second element is below (1), above (-1) or in line with (0) the first
+    fn down(&self) -> i32 {
+        self.second.period.cmp(&self.first.period) as i32
+    }
+
+    // Gets the expected sign of (second - first) for a property, or None if the trends compete or don't apply
+    // Effective nuclear charge rises across a period, pulling electrons in, while each period down adds a shell
+    fn expected(&self, property: Property) -> Option<i32> {
+        let trend = match property {
+            Property::Electronegativity | Property::IonizationEnergy | Property::ElectronAffinity => self.across() - self.down(),
+            Property::AtomicRadius | Property::CovalentRadius => self.down() - self.across(),
+            // Ionic radii depend on the charge, and the bulk properties on bonding in the solid
+            _ => return None,
+        };
+
+        (trend != 0).then_some(trend.signum())
+    }
+}
+
+impl Display for ElementComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (first, second) = (self.first, self.second);
+        let position = |element: &Element| match element.group {
+            Some(group) => format!("group {}, period {}", group, element.period),
+            None => format!("period {}, {}-block", element.period, element.block),
+        };
+
+        writeln!(f, "Comparing {} ({}) with {} ({})", first.name, position(first), second.name, position(second))?;
+
+        let across = match self.across() {
+            1 => Some(format!("{} is further right in the table, so its nuclear charge is higher while its outer electrons are shielded about as well, giving a higher effective nuclear charge", second.symbol)),
+            -1 => Some(format!("{} is further left in the table, so its nuclear charge is lower while its outer electrons are shielded about as well, giving a lower effective nuclear charge", second.symbol)),
+            _ => None,
+        };
+        let down = match self.down() {
+            1 => Some(format!("{} is further down the table, so its valence electrons are in a higher shell, further from the nucleus", second.symbol)),
+            -1 => Some(format!("{} is further up the table, so its valence electrons are in a lower shell, closer to the nucleus", second.symbol)),
+            _ => None,
+        };
+
+        match (across, down) {
+            (None, None) => write!(f, "They are the same element, so there is no trend to compare")?,
+            (Some(across), Some(down)) if self.across() == self.down() => write!(f, "{}, but {}; these trends compete, so the measured values decide", across, down)?,
+            (Some(across), Some(down)) => write!(f, "{}, and {}; both trends point the same way", across, down)?,
+            (Some(trend), None) | (None, Some(trend)) => write!(f, "{}", trend)?,
+        }
+
+        for property in Property::ALL {
+            let (Some(a), Some(b)) = (property.value(first), property.value(second)) else {
+                write!(f, "\n{}: not known for both elements", property)?;
+                continue;
+            };
+
+            write!(f, "\n{}: {} {} {}, {} {} {}", property, first.symbol, a, property.unit(), second.symbol, b, property.unit())?;
+
+            let actual = b.total_cmp(&a) as i32;
+            match self.expected(property) {
+                Some(expected) if actual == expected => write!(f, " (as expected)")?,
+                Some(_) => write!(f, " (against the trend)")?,
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comparison(first: &str, second: &str) -> ElementComparison {
+        ElementComparison::new(Element::from_symbol(first).unwrap(), Element::from_symbol(second).unwrap())
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!((comparison("Na", "Cl").across(), comparison("Na", "Cl").down()), (1, 0));
+        assert_eq!((comparison("F", "I").across(), comparison("F", "I").down()), (0, 1));
+        assert_eq!((comparison("Ba", "Ce").across(), comparison("Ba", "Ce").down()), (1, 0));
+        assert_eq!((comparison("Ce", "Hf").across(), comparison("Ce", "Hf").down()), (1, 0));
+        assert_eq!((comparison("Li", "Mg").across(), comparison("Li", "Mg").down()), (1, 1));
+    }
+
+    #[test]
+    fn test_expected_trends() {
+        let across = comparison("Na", "Cl");
+        assert_eq!(across.expected(Property::Electronegativity), Some(1));
+        assert_eq!(across.expected(Property::AtomicRadius), Some(-1));
+        assert_eq!(across.expected(Property::Density), None);
+
+        let down = comparison("F", "I");
+        assert_eq!(down.expected(Property::IonizationEnergy), Some(-1));
+        assert_eq!(down.expected(Property::CovalentRadius), Some(1));
+
+        // Diagonal neighbours have competing trends
+        assert_eq!(comparison("Li", "Mg").expected(Property::Electronegativity), None);
+    }
+
+    #[test]
+    fn test_comparison_output() {
+        let output = comparison("Na", "Cl").to_string();
+
+        assert!(output.starts_with("Comparing Sodium (group 1, period 3) with Chlorine (group 17, period 3)"));
+        assert!(output.contains("Electronegativity: Na 0.93 (Pauling), Cl 3.16 (Pauling) (as expected)"));
+        assert!(output.contains("Atomic radius: Na 180 pm, Cl 100 pm (as expected)"));
+    }
+}